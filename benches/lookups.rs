@@ -0,0 +1,78 @@
+//! Benchmarks for the main lookup paths; run with `cargo bench`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use usb_ids::{Device, FromId, Vendor, Vendors};
+
+fn vendor_lookups(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vendors");
+    group.throughput(Throughput::Elements(1));
+
+    group.bench_function("Vendor::from_id (known)", |b| {
+        b.iter(|| Vendor::from_id(black_box(0x1d6b)))
+    });
+    group.bench_function("Vendor::from_id (unknown)", |b| {
+        b.iter(|| Vendor::from_id(black_box(0xfffe)))
+    });
+    // Canon has more devices than any other vendor
+    group.bench_function("Device::from_vid_pid (large vendor)", |b| {
+        b.iter(|| Device::from_vid_pid(black_box(0x04a9), black_box(0x3302)))
+    });
+    // Much faster with the `name-index` feature
+    group.bench_function("Vendor::from_name", |b| {
+        b.iter(|| Vendor::from_name(black_box("Linux Foundation")))
+    });
+
+    group.finish();
+
+    let mut group = c.benchmark_group("iteration");
+    group.throughput(Throughput::Elements(usb_ids::total_vendor_count() as u64));
+    group.bench_function("Vendors::iter().count()", |b| {
+        b.iter(|| Vendors::iter().count())
+    });
+    group.finish();
+}
+
+#[cfg(not(feature = "vendors-only"))]
+fn class_lookups(c: &mut Criterion) {
+    use usb_ids::{Protocol, SubClass};
+
+    let mut group = c.benchmark_group("classes");
+    group.throughput(Throughput::Elements(1));
+
+    group.bench_function("SubClass::from_cid_scid", |b| {
+        b.iter(|| SubClass::from_cid_scid(black_box(0x02), black_box(0x02)))
+    });
+    group.bench_function("Protocol::from_cid_scid_pid", |b| {
+        b.iter(|| Protocol::from_cid_scid_pid(black_box(0x02), black_box(0x02), black_box(0x05)))
+    });
+
+    group.finish();
+}
+
+#[cfg(not(feature = "no-hid"))]
+fn hid_lookups(c: &mut Criterion) {
+    use usb_ids::HidUsage;
+
+    let mut group = c.benchmark_group("hid");
+    group.throughput(Throughput::Elements(1));
+
+    // Consumer is the largest usage page
+    group.bench_function("HidUsage::from_pageid_uid", |b| {
+        b.iter(|| HidUsage::from_pageid_uid(black_box(0x0c), black_box(0x002)))
+    });
+
+    group.finish();
+}
+
+#[cfg(not(feature = "vendors-only"))]
+fn non_vendor_lookups(c: &mut Criterion) {
+    class_lookups(c);
+    #[cfg(not(feature = "no-hid"))]
+    hid_lookups(c);
+}
+
+#[cfg(feature = "vendors-only")]
+fn non_vendor_lookups(_: &mut Criterion) {}
+
+criterion_group!(benches, vendor_lookups, non_vendor_lookups);
+criterion_main!(benches);
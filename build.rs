@@ -1,10 +1,13 @@
+use std::collections::HashMap;
 use std::env;
 use std::fs;
-use std::io::{BufRead, BufReader, BufWriter, Write};
-use std::path::Path;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
 
-use phf_codegen::Map;
+use nom::Offset;
+use phf_codegen::OrderedMap;
 use quote::quote;
+use sha2::{Digest, Sha256};
 
 /* This build script contains a "parser" for the USB ID database.
  * "Parser" is in scare-quotes because it's really a line matcher with a small amount
@@ -12,20 +15,47 @@ use quote::quote;
  */
 
 // these are the definitions for the generated maps that will be written to the source file
-const VENDOR_PROLOGUE: &str = "static USB_IDS: phf::Map<u16, Vendor> = ";
-const CLASS_PROLOGUE: &str = "static USB_CLASSES: phf::Map<u8, Class> = ";
-const AUDIO_TERMINAL_PROLOGUE: &str = "static USB_AUDIO_TERMINALS: phf::Map<u16, AudioTerminal> = ";
-const HID_ID_PROLOGUE: &str = "static USB_HID_IDS: phf::Map<u8, Hid> = ";
-const HID_R_PROLOGUE: &str = "static USB_HID_R_TYPES: phf::Map<u8, HidItemType> = ";
-const BIAS_PROLOGUE: &str = "static USB_BIASES: phf::Map<u8, Bias> = ";
-const PHY_PROLOGUE: &str = "static USB_PHYS: phf::Map<u8, Phy> = ";
-const HUT_PROLOGUE: &str = "static USB_HUTS: phf::Map<u8, HidUsagePage> = ";
-const LANG_PROLOGUE: &str = "static USB_LANGS: phf::Map<u16, Language> = ";
-const HID_CC_PROLOGUE: &str = "static USB_HID_CCS: phf::Map<u8, HidCountryCode> = ";
-const TERMINAL_PROLOGUE: &str = "static USB_VIDEO_TERMINALS: phf::Map<u16, VideoTerminal> = ";
+const VENDOR_PROLOGUE: &str = "static USB_IDS: phf::OrderedMap<u16, Vendor> = ";
+const DEVICE_PROLOGUE: &str = "static USB_DEVICES: phf::OrderedMap<u32, Device> = ";
+const CLASS_PROLOGUE: &str = "static USB_CLASSES: phf::OrderedMap<u8, Class> = ";
+const SUBCLASS_PROLOGUE: &str = "static USB_SUBCLASSES: phf::OrderedMap<u16, SubClass> = ";
+const PROTOCOL_PROLOGUE: &str = "static USB_PROTOCOLS: phf::OrderedMap<u32, Protocol> = ";
+const AUDIO_TERMINAL_PROLOGUE: &str =
+    "static USB_AUDIO_TERMINALS: phf::OrderedMap<u16, AudioTerminal> = ";
+const HID_ID_PROLOGUE: &str = "static USB_HID_IDS: phf::OrderedMap<u8, Hid> = ";
+const HID_R_PROLOGUE: &str = "static USB_HID_R_TYPES: phf::OrderedMap<u8, HidItemType> = ";
+const BIAS_PROLOGUE: &str = "static USB_BIASES: phf::OrderedMap<u8, Bias> = ";
+const PHY_PROLOGUE: &str = "static USB_PHYS: phf::OrderedMap<u8, Phy> = ";
+const HUT_PROLOGUE: &str = "static USB_HUTS: phf::OrderedMap<u8, HidUsagePage> = ";
+const LANG_PROLOGUE: &str = "static USB_LANGS: phf::OrderedMap<u16, Language> = ";
+const HID_CC_PROLOGUE: &str = "static USB_HID_CCS: phf::OrderedMap<u8, HidCountryCode> = ";
+const TERMINAL_PROLOGUE: &str =
+    "static USB_VIDEO_TERMINALS: phf::OrderedMap<u16, VideoTerminal> = ";
+
+/// Running totals of a section's top-level entries, their children, and their children's
+/// children, emitted as `*_COUNT` constants alongside the section's maps.
+#[derive(Default)]
+struct Counts {
+    parents: usize,
+    children: usize,
+    grandchildren: usize,
+}
+
+impl Counts {
+    /// Count a top-level entry and its children
+    fn add<T>(&mut self, entry: &impl CgEntry<T>) {
+        self.parents += 1;
+        self.children += entry.children();
+    }
+}
 
 trait CgEntry<T> {
     fn id(&self) -> T;
+
+    /// The number of children the entry has, if it can have any
+    fn children(&self) -> usize {
+        0
+    }
 }
 
 struct CgVendor {
@@ -40,6 +70,58 @@ struct CgDevice {
     interfaces: Vec<CgInterface>,
 }
 
+impl CgVendor {
+    /// Emit the vendor to the vendor map, and each of its devices to the flat device map
+    fn emit(
+        &self,
+        vendors: &mut OrderedMap<u16>,
+        devices: &mut OrderedMap<u32>,
+        counts: &mut Counts,
+    ) {
+        vendors.entry(self.id, &quote!(#self).to_string());
+        counts.parents += 1;
+        counts.children += self.devices.len();
+        for device in &self.devices {
+            counts.grandchildren += device.interfaces.len();
+            let key = (self.id as u32) << 16 | device.id as u32;
+            devices.entry(key, &device.to_tokens(self.id).to_string());
+        }
+    }
+}
+
+impl CgDevice {
+    fn to_tokens(&self, vendor_id: u16) -> proc_macro2::TokenStream {
+        let CgDevice {
+            id: device_id,
+            name,
+            interfaces,
+        } = self;
+
+        // The database doesn't record interfaces' classes, so they're all unspecified (0)
+        let interfaces = interfaces.iter().map(|CgInterface { id, name }| {
+            quote! {
+                Interface {
+                    vendor_id: #vendor_id,
+                    device_id: #device_id,
+                    id: #id,
+                    class_id: 0,
+                    subclass_id: 0,
+                    protocol_id: 0,
+                    name: #name
+                }
+            }
+        });
+        quote! {
+            Device {
+                vendor_id: #vendor_id,
+                id: #device_id,
+                name: #name,
+                interfaces: &[#(#interfaces),*],
+            }
+        }
+    }
+}
+
 struct CgClass {
     id: u8,
     name: String,
@@ -48,16 +130,75 @@ struct CgClass {
 
 type CgSubClass = CgParentType<u8, CgProtocol>;
 
+impl CgClass {
+    /// Emit the class to the class map, and each of its subclasses and protocols to the
+    /// flat subclass and protocol maps
+    fn emit(
+        &self,
+        classes: &mut OrderedMap<u8>,
+        sub_classes: &mut OrderedMap<u16>,
+        protocols: &mut OrderedMap<u32>,
+        counts: &mut Counts,
+    ) {
+        classes.entry(self.id, &quote!(#self).to_string());
+        counts.parents += 1;
+        counts.children += self.sub_classes.len();
+        for sub_class in &self.sub_classes {
+            counts.grandchildren += sub_class.children.len();
+            let key = (self.id as u16) << 8 | sub_class.id as u16;
+            sub_classes.entry(key, &sub_class.to_tokens(self.id).to_string());
+
+            for protocol in &sub_class.children {
+                let key = (self.id as u32) << 16 | (sub_class.id as u32) << 8 | protocol.id as u32;
+                let tokens = protocol_tokens(self.id, sub_class.id, protocol);
+                protocols.entry(key, &tokens.to_string());
+            }
+        }
+    }
+}
+
+fn protocol_tokens(
+    class_id: u8,
+    subclass_id: u8,
+    protocol: &CgProtocol,
+) -> proc_macro2::TokenStream {
+    let CgProtocol { id, name } = protocol;
+    quote! {
+        Protocol { class_id: #class_id, subclass_id: #subclass_id, id: #id, name: #name }
+    }
+}
+
+impl CgSubClass {
+    fn to_tokens(&self, class_id: u8) -> proc_macro2::TokenStream {
+        let CgParentType {
+            id: subclass_id,
+            name,
+            children,
+        } = self;
+
+        let protocols = children
+            .iter()
+            .map(|protocol| protocol_tokens(class_id, *subclass_id, protocol));
+        quote! {
+            SubClass { class_id: #class_id, id: #subclass_id, name: #name, protocols: &[#(#protocols),*] }
+        }
+    }
+}
+
 struct CgParentType<T, C> {
     id: T,
     name: String,
     children: Vec<C>,
 }
 
-impl<T: Copy, C: CgEntry<T>> CgEntry<T> for CgParentType<T, C> {
+impl<T: Copy, C> CgEntry<T> for CgParentType<T, C> {
     fn id(&self) -> T {
         self.id
     }
+
+    fn children(&self) -> usize {
+        self.children.len()
+    }
 }
 
 struct CgType<T> {
@@ -85,64 +226,174 @@ type CgLang = CgParentType<u16, CgDialect>;
 type CgCountryCode = CgType<u8>;
 type CgTerminalType = CgType<u16>;
 
+/// Why a line couldn't be parsed, and the (0-based) offset into it at which parsing stopped
+struct Rejection {
+    reason: &'static str,
+    offset: usize,
+}
+
+impl Rejection {
+    /// Rejects `line` at the furthest point reached by any of the parsers that failed on it
+    fn new<'a>(
+        reason: &'static str,
+        line: &'a str,
+        errors: &[nom::Err<nom::error::Error<&'a str>>],
+    ) -> Self {
+        let offset = errors
+            .iter()
+            .map(|err| match err {
+                nom::Err::Error(e) | nom::Err::Failure(e) => line.offset(e.input),
+                nom::Err::Incomplete(_) => line.len(),
+            })
+            .max()
+            .unwrap_or(0);
+        Rejection { reason, offset }
+    }
+}
+
+/// A line rejected without being parsed (e.g. a device with no vendor) fails at its start
+impl From<&'static str> for Rejection {
+    fn from(reason: &'static str) -> Self {
+        Rejection { reason, offset: 0 }
+    }
+}
+
 /// Parser state parses only the type for the current section, this is because some
 /// parsers are ambiguous without context; device.interface == subclass.protocol for example.
 enum ParserState {
-    Vendors(Map<u16>, Option<CgVendor>, u16),
-    Classes(Map<u8>, Option<CgClass>, u8),
-    AtType(Map<u16>, Option<CgAtType>),
-    HidType(Map<u8>, Option<CgHidType>),
-    RType(Map<u8>, Option<CgRType>),
-    BiasType(Map<u8>, Option<CgRBiasType>),
-    PhyType(Map<u8>, Option<CgPhyType>),
-    HutType(Map<u8>, Option<CgHut>),
-    Lang(Map<u16>, Option<CgLang>),
-    CountryCode(Map<u8>, Option<CgCountryCode>),
-    TerminalType(Map<u16>, Option<CgTerminalType>),
+    // The trailing map holds the names of the vendors seen so far, to catch duplicates
+    Vendors(
+        OrderedMap<u16>,
+        Option<CgVendor>,
+        u16,
+        OrderedMap<u32>,
+        Counts,
+        HashMap<u16, String>,
+    ),
+    Classes(
+        OrderedMap<u8>,
+        Option<CgClass>,
+        u8,
+        OrderedMap<u16>,
+        OrderedMap<u32>,
+        Counts,
+    ),
+    AtType(OrderedMap<u16>, Option<CgAtType>, Counts),
+    HidType(OrderedMap<u8>, Option<CgHidType>, Counts),
+    RType(OrderedMap<u8>, Option<CgRType>, Counts),
+    BiasType(OrderedMap<u8>, Option<CgRBiasType>, Counts),
+    PhyType(OrderedMap<u8>, Option<CgPhyType>, Counts),
+    HutType(OrderedMap<u8>, Option<CgHut>, Counts),
+    Lang(OrderedMap<u16>, Option<CgLang>, Counts),
+    CountryCode(OrderedMap<u8>, Option<CgCountryCode>, Counts),
+    TerminalType(OrderedMap<u16>, Option<CgTerminalType>, Counts),
 }
 
 impl ParserState {
+    fn new_vendors() -> Self {
+        ParserState::Vendors(
+            OrderedMap::<u16>::new(),
+            None,
+            0u16,
+            OrderedMap::<u32>::new(),
+            Counts::default(),
+            HashMap::new(),
+        )
+    }
+
+    fn new_classes() -> Self {
+        ParserState::Classes(
+            OrderedMap::<u8>::new(),
+            None,
+            0u8,
+            OrderedMap::<u16>::new(),
+            OrderedMap::<u32>::new(),
+            Counts::default(),
+        )
+    }
+
+    /// Whether the current state's section should be emitted, based on the crate's features
+    fn enabled(&self) -> bool {
+        let omitted = match self {
+            ParserState::Vendors(..) => return true,
+            ParserState::Classes(..) => "CARGO_FEATURE_VENDORS_ONLY",
+            ParserState::AtType(..) => "CARGO_FEATURE_NO_AUDIO",
+            ParserState::TerminalType(..) => "CARGO_FEATURE_NO_VIDEO",
+            ParserState::Lang(..) => "CARGO_FEATURE_NO_LANGUAGES",
+            ParserState::HidType(..)
+            | ParserState::RType(..)
+            | ParserState::BiasType(..)
+            | ParserState::PhyType(..)
+            | ParserState::HutType(..)
+            | ParserState::CountryCode(..) => "CARGO_FEATURE_NO_HID",
+        };
+
+        env::var_os(omitted).is_none()
+    }
+
     /// Return the prologue string for the current state; the type definition
     fn prologue_str(&self) -> &'static str {
         match self {
-            ParserState::Vendors(_, _, _) => VENDOR_PROLOGUE,
-            ParserState::Classes(_, _, _) => CLASS_PROLOGUE,
-            ParserState::AtType(_, _) => AUDIO_TERMINAL_PROLOGUE,
-            ParserState::HidType(_, _) => HID_ID_PROLOGUE,
-            ParserState::RType(_, _) => HID_R_PROLOGUE,
-            ParserState::BiasType(_, _) => BIAS_PROLOGUE,
-            ParserState::PhyType(_, _) => PHY_PROLOGUE,
-            ParserState::HutType(_, _) => HUT_PROLOGUE,
-            ParserState::Lang(_, _) => LANG_PROLOGUE,
-            ParserState::CountryCode(_, _) => HID_CC_PROLOGUE,
-            ParserState::TerminalType(_, _) => TERMINAL_PROLOGUE,
+            ParserState::Vendors(_, _, _, _, _, _) => VENDOR_PROLOGUE,
+            ParserState::Classes(_, _, _, _, _, _) => CLASS_PROLOGUE,
+            ParserState::AtType(_, _, _) => AUDIO_TERMINAL_PROLOGUE,
+            ParserState::HidType(_, _, _) => HID_ID_PROLOGUE,
+            ParserState::RType(_, _, _) => HID_R_PROLOGUE,
+            ParserState::BiasType(_, _, _) => BIAS_PROLOGUE,
+            ParserState::PhyType(_, _, _) => PHY_PROLOGUE,
+            ParserState::HutType(_, _, _) => HUT_PROLOGUE,
+            ParserState::Lang(_, _, _) => LANG_PROLOGUE,
+            ParserState::CountryCode(_, _, _) => HID_CC_PROLOGUE,
+            ParserState::TerminalType(_, _, _) => TERMINAL_PROLOGUE,
+        }
+    }
+
+    /// Return a human-readable name for the current state's section, for diagnostics
+    fn section_name(&self) -> &'static str {
+        match self {
+            ParserState::Vendors(_, _, _, _, _, _) => "vendors",
+            ParserState::Classes(_, _, _, _, _, _) => "classes",
+            ParserState::AtType(_, _, _) => "audio terminal types",
+            ParserState::HidType(_, _, _) => "HID descriptor types",
+            ParserState::RType(_, _, _) => "HID item types",
+            ParserState::BiasType(_, _, _) => "biases",
+            ParserState::PhyType(_, _, _) => "physical descriptor items",
+            ParserState::HutType(_, _, _) => "HID usage pages",
+            ParserState::Lang(_, _, _) => "languages",
+            ParserState::CountryCode(_, _, _) => "HID country codes",
+            ParserState::TerminalType(_, _, _) => "video terminal types",
         }
     }
 
     /// Emit any pending entries to the map
     fn emit(&mut self) {
         match self {
-            ParserState::Vendors(m, Some(vendor), _) => {
-                m.entry(vendor.id, &quote!(#vendor).to_string());
+            ParserState::Vendors(m, Some(vendor), _, dm, counts, _) => {
+                vendor.emit(m, dm, counts);
             }
-            ParserState::Classes(m, Some(class), _) => {
-                m.entry(class.id, &quote!(#class).to_string());
+            ParserState::Classes(m, Some(class), _, sm, pm, counts) => {
+                class.emit(m, sm, pm, counts);
             }
-            ParserState::AtType(m, Some(t)) | ParserState::TerminalType(m, Some(t)) => {
+            ParserState::AtType(m, Some(t), counts)
+            | ParserState::TerminalType(m, Some(t), counts) => {
                 m.entry(t.id(), &quote!(#t).to_string());
+                counts.add(t);
             }
-            ParserState::HidType(m, Some(t))
-            | ParserState::RType(m, Some(t))
-            | ParserState::BiasType(m, Some(t))
-            | ParserState::CountryCode(m, Some(t))
-            | ParserState::PhyType(m, Some(t)) => {
+            ParserState::HidType(m, Some(t), counts)
+            | ParserState::RType(m, Some(t), counts)
+            | ParserState::BiasType(m, Some(t), counts)
+            | ParserState::CountryCode(m, Some(t), counts)
+            | ParserState::PhyType(m, Some(t), counts) => {
                 m.entry(t.id(), &quote!(#t).to_string());
+                counts.add(t);
             }
-            ParserState::HutType(m, Some(t)) => {
+            ParserState::HutType(m, Some(t), counts) => {
                 m.entry(t.id, &quote!(#t).to_string());
+                counts.add(t);
             }
-            ParserState::Lang(m, Some(t)) => {
+            ParserState::Lang(m, Some(t), counts) => {
                 m.entry(t.id, &quote!(#t).to_string());
+                counts.add(t);
             }
             _ => {}
         }
@@ -152,68 +403,126 @@ impl ParserState {
     ///
     /// Not very efficient but since it only checks # lines and required length it is not terrible
     fn next_from_header(&mut self, line: &str, output: &mut impl Write) -> Option<ParserState> {
-        if line.len() < 7 || !line.starts_with('#') {
+        if !line.starts_with('#') {
             return None;
         }
 
-        match &line[..7] {
+        // Comments can contain any text, so the prefix may not end on a character boundary
+        match line.get(..7)? {
             "# C cla" => {
                 self.finalize(output);
-                Some(ParserState::Classes(Map::<u8>::new(), None, 0u8))
+                Some(ParserState::new_classes())
             }
             "# AT te" => {
                 self.finalize(output);
-                Some(ParserState::AtType(Map::<u16>::new(), None))
+                Some(ParserState::AtType(
+                    OrderedMap::<u16>::new(),
+                    None,
+                    Counts::default(),
+                ))
             }
             "# HID d" => {
                 self.finalize(output);
-                Some(ParserState::HidType(Map::<u8>::new(), None))
+                Some(ParserState::HidType(
+                    OrderedMap::<u8>::new(),
+                    None,
+                    Counts::default(),
+                ))
             }
             "# R ite" => {
                 self.finalize(output);
-                Some(ParserState::RType(Map::<u8>::new(), None))
+                Some(ParserState::RType(
+                    OrderedMap::<u8>::new(),
+                    None,
+                    Counts::default(),
+                ))
             }
             "# BIAS " => {
                 self.finalize(output);
-                Some(ParserState::BiasType(Map::<u8>::new(), None))
+                Some(ParserState::BiasType(
+                    OrderedMap::<u8>::new(),
+                    None,
+                    Counts::default(),
+                ))
             }
             "# PHY i" => {
                 self.finalize(output);
-                Some(ParserState::PhyType(Map::<u8>::new(), None))
+                Some(ParserState::PhyType(
+                    OrderedMap::<u8>::new(),
+                    None,
+                    Counts::default(),
+                ))
             }
             "# HUT h" => {
                 self.finalize(output);
-                Some(ParserState::HutType(Map::<u8>::new(), None))
+                Some(ParserState::HutType(
+                    OrderedMap::<u8>::new(),
+                    None,
+                    Counts::default(),
+                ))
             }
             "# L lan" => {
                 self.finalize(output);
-                Some(ParserState::Lang(Map::<u16>::new(), None))
+                Some(ParserState::Lang(
+                    OrderedMap::<u16>::new(),
+                    None,
+                    Counts::default(),
+                ))
             }
             "# HCC c" => {
                 self.finalize(output);
-                Some(ParserState::CountryCode(Map::<u8>::new(), None))
+                Some(ParserState::CountryCode(
+                    OrderedMap::<u8>::new(),
+                    None,
+                    Counts::default(),
+                ))
             }
             "# VT te" => {
                 self.finalize(output);
-                Some(ParserState::TerminalType(Map::<u16>::new(), None))
+                Some(ParserState::TerminalType(
+                    OrderedMap::<u16>::new(),
+                    None,
+                    Counts::default(),
+                ))
+            }
+            _ => {
+                // A new section we don't know about would otherwise be silently parsed as
+                // part of the current one
+                if parser::section_header(line).is_ok() {
+                    println!("cargo:warning=Unknown section header: {:?}", line);
+                }
+                None
             }
-            _ => None,
         }
     }
 
-    /// Process a line of input for the current state
-    fn process(&mut self, line: &str) {
+    /// Process a line of input for the current state, returning why (and where) it couldn't
+    /// be parsed on failure
+    fn process(&mut self, line: &str) -> Result<(), Rejection> {
         if line.is_empty() || line.starts_with('#') {
-            return;
+            return Ok(());
         }
 
         // Switch parser state based on line prefix and current state
         // this relies on ordering of classes and types in the file...
         match self {
-            ParserState::Vendors(m, ref mut curr_vendor, ref mut curr_device_id) => {
+            ParserState::Vendors(
+                m,
+                ref mut curr_vendor,
+                ref mut curr_device_id,
+                dm,
+                counts,
+                seen,
+            ) => {
                 if let Ok((name, id)) = parser::vendor(line) {
+                    // phf can't hold duplicate keys, and silently picking one would hide a
+                    // corrupt database
+                    if let Some(original) = seen.insert(id, name.into()) {
+                        panic!("Duplicate vendor {:04x}: {:?} and {:?}", id, original, name);
+                    }
+
                     if let Some(cv) = curr_vendor {
-                        m.entry(cv.id, &quote!(#cv).to_string());
+                        cv.emit(m, dm, counts);
                     }
 
                     // Set our new vendor as the current vendor.
@@ -226,32 +535,50 @@ impl ParserState {
                 } else {
                     let curr_vendor = curr_vendor
                         .as_mut()
-                        .expect("No parent vendor whilst parsing vendors");
-                    if let Ok((name, id)) = parser::device(line) {
-                        curr_vendor.devices.push(CgDevice {
-                            id,
-                            name: name.into(),
-                            interfaces: vec![],
-                        });
-                        *curr_device_id = id;
-                    } else if let Ok((name, id)) = parser::interface(line) {
-                        let curr_device = curr_vendor
-                            .devices
-                            .iter_mut()
-                            .find(|d| d.id == *curr_device_id)
-                            .expect("No parent device whilst parsing interfaces");
-
-                        curr_device.interfaces.push(CgInterface {
-                            id,
-                            name: name.into(),
-                        });
+                        .ok_or("no parent vendor whilst parsing vendors")?;
+                    match (parser::device(line), parser::interface(line)) {
+                        (Ok((name, id)), _) => {
+                            if let Some(original) = curr_vendor.devices.iter().find(|d| d.id == id)
+                            {
+                                panic!(
+                                    "Duplicate device {:04x}:{:04x}: {:?} and {:?}",
+                                    curr_vendor.id, id, original.name, name
+                                );
+                            }
+
+                            curr_vendor.devices.push(CgDevice {
+                                id,
+                                name: name.into(),
+                                interfaces: vec![],
+                            });
+                            *curr_device_id = id;
+                        }
+                        (_, Ok((name, id))) => {
+                            let curr_device = curr_vendor
+                                .devices
+                                .iter_mut()
+                                .find(|d| d.id == *curr_device_id)
+                                .ok_or("no parent device whilst parsing interfaces")?;
+
+                            curr_device.interfaces.push(CgInterface {
+                                id,
+                                name: name.into(),
+                            });
+                        }
+                        (Err(device), Err(interface)) => {
+                            return Err(Rejection::new(
+                                "invalid device or interface line",
+                                line,
+                                &[device, interface],
+                            ));
+                        }
                     }
                 }
             }
-            ParserState::Classes(m, ref mut curr_class, ref mut curr_class_id) => {
+            ParserState::Classes(m, ref mut curr_class, ref mut curr_class_id, sm, pm, counts) => {
                 if let Ok((name, id)) = parser::class(line) {
                     if let Some(cv) = curr_class {
-                        m.entry(cv.id, &quote!(#cv).to_string());
+                        cv.emit(m, sm, pm, counts);
                     }
 
                     // Set our new class as the current class.
@@ -263,33 +590,44 @@ impl ParserState {
                 } else {
                     let curr_class = curr_class
                         .as_mut()
-                        .expect("No parent class whilst parsing classes");
-                    if let Ok((name, id)) = parser::sub_class(line) {
-                        curr_class.sub_classes.push(CgSubClass {
-                            id,
-                            name: name.into(),
-                            children: vec![],
-                        });
-                        *curr_class_id = id;
-                    } else if let Ok((name, id)) = parser::protocol(line) {
-                        let curr_device = curr_class
-                            .sub_classes
-                            .iter_mut()
-                            .find(|d| d.id == *curr_class_id)
-                            .expect("No parent sub-class whilst parsing protocols");
-
-                        curr_device.children.push(CgProtocol {
-                            id,
-                            name: name.into(),
-                        });
+                        .ok_or("no parent class whilst parsing classes")?;
+                    match (parser::sub_class(line), parser::protocol(line)) {
+                        (Ok((name, id)), _) => {
+                            curr_class.sub_classes.push(CgSubClass {
+                                id,
+                                name: name.into(),
+                                children: vec![],
+                            });
+                            *curr_class_id = id;
+                        }
+                        (_, Ok((name, id))) => {
+                            let curr_device = curr_class
+                                .sub_classes
+                                .iter_mut()
+                                .find(|d| d.id == *curr_class_id)
+                                .ok_or("no parent sub-class whilst parsing protocols")?;
+
+                            curr_device.children.push(CgProtocol {
+                                id,
+                                name: name.into(),
+                            });
+                        }
+                        (Err(sub_class), Err(protocol)) => {
+                            return Err(Rejection::new(
+                                "invalid subclass or protocol line",
+                                line,
+                                &[sub_class, protocol],
+                            ));
+                        }
                     }
                 }
             }
-            ParserState::AtType(m, ref mut current) => {
-                let (name, id) =
-                    parser::audio_terminal_type(line).expect("Invalid audio terminal line");
+            ParserState::AtType(m, ref mut current, counts) => {
+                let (name, id) = parser::audio_terminal_type(line)
+                    .map_err(|e| Rejection::new("invalid audio terminal line", line, &[e]))?;
                 if let Some(cv) = current {
                     m.entry(cv.id, &quote!(#cv).to_string());
+                    counts.add(cv);
                 }
 
                 // Set our new class as the current class.
@@ -298,10 +636,12 @@ impl ParserState {
                     name: name.into(),
                 });
             }
-            ParserState::HidType(m, ref mut current) => {
-                let (name, id) = parser::hid_type(line).expect("Invalid hid type line");
+            ParserState::HidType(m, ref mut current, counts) => {
+                let (name, id) = parser::hid_type(line)
+                    .map_err(|e| Rejection::new("invalid hid type line", line, &[e]))?;
                 if let Some(cv) = current {
                     m.entry(cv.id, &quote!(#cv).to_string());
+                    counts.add(cv);
                 }
 
                 // Set our new class as the current class.
@@ -310,10 +650,12 @@ impl ParserState {
                     name: name.into(),
                 });
             }
-            ParserState::RType(m, ref mut current) => {
-                let (name, id) = parser::hid_item_type(line).expect("Invalid hid item type line");
+            ParserState::RType(m, ref mut current, counts) => {
+                let (name, id) = parser::hid_item_type(line)
+                    .map_err(|e| Rejection::new("invalid hid item type line", line, &[e]))?;
                 if let Some(cv) = current {
                     m.entry(cv.id, &quote!(#cv).to_string());
+                    counts.add(cv);
                 }
 
                 // Set our new class as the current class.
@@ -322,10 +664,12 @@ impl ParserState {
                     name: name.into(),
                 });
             }
-            ParserState::BiasType(m, ref mut current) => {
-                let (name, id) = parser::bias_type(line).expect("Invalid bias type line");
+            ParserState::BiasType(m, ref mut current, counts) => {
+                let (name, id) = parser::bias_type(line)
+                    .map_err(|e| Rejection::new("invalid bias type line", line, &[e]))?;
                 if let Some(cv) = current {
                     m.entry(cv.id, &quote!(#cv).to_string());
+                    counts.add(cv);
                 }
 
                 // Set our new class as the current class.
@@ -334,10 +678,12 @@ impl ParserState {
                     name: name.into(),
                 });
             }
-            ParserState::PhyType(m, ref mut current) => {
-                let (name, id) = parser::phy_type(line).expect("Invalid phy type line");
+            ParserState::PhyType(m, ref mut current, counts) => {
+                let (name, id) = parser::phy_type(line)
+                    .map_err(|e| Rejection::new("invalid phy type line", line, &[e]))?;
                 if let Some(cv) = current {
                     m.entry(cv.id, &quote!(#cv).to_string());
+                    counts.add(cv);
                 }
 
                 // Set our new class as the current class.
@@ -346,10 +692,11 @@ impl ParserState {
                     name: name.into(),
                 });
             }
-            ParserState::HutType(m, ref mut current) => {
+            ParserState::HutType(m, ref mut current, counts) => {
                 if let Ok((name, id)) = parser::hut_type(line) {
                     if let Some(cv) = current {
                         m.entry(cv.id, &quote!(#cv).to_string());
+                        counts.add(cv);
                     }
 
                     // Set our new class as the current class.
@@ -359,19 +706,22 @@ impl ParserState {
                         children: vec![],
                     });
                 } else {
-                    let curr_hut = current.as_mut().expect("No parent hut whilst parsing huts");
-                    if let Ok((name, id)) = parser::hid_usage_name(line) {
-                        curr_hut.children.push(CgHidUsage {
-                            id,
-                            name: name.into(),
-                        });
-                    }
+                    let curr_hut = current
+                        .as_mut()
+                        .ok_or("no parent hut whilst parsing huts")?;
+                    let (name, id) = parser::hid_usage_name(line)
+                        .map_err(|e| Rejection::new("invalid hid usage line", line, &[e]))?;
+                    curr_hut.children.push(CgHidUsage {
+                        id,
+                        name: name.into(),
+                    });
                 }
             }
-            ParserState::Lang(m, ref mut current) => {
+            ParserState::Lang(m, ref mut current, counts) => {
                 if let Ok((name, id)) = parser::language(line) {
                     if let Some(cv) = current {
                         m.entry(cv.id, &quote!(#cv).to_string());
+                        counts.add(cv);
                     }
 
                     // Set our new class as the current class.
@@ -383,19 +733,21 @@ impl ParserState {
                 } else {
                     let curr_lang = current
                         .as_mut()
-                        .expect("No parent lang whilst parsing langs");
-                    if let Ok((name, id)) = parser::dialect(line) {
-                        curr_lang.children.push(CgDialect {
-                            id,
-                            name: name.into(),
-                        });
-                    }
+                        .ok_or("no parent lang whilst parsing langs")?;
+                    let (name, id) = parser::dialect(line)
+                        .map_err(|e| Rejection::new("invalid dialect line", line, &[e]))?;
+                    curr_lang.children.push(CgDialect {
+                        id,
+                        name: name.into(),
+                    });
                 }
             }
-            ParserState::CountryCode(m, ref mut current) => {
-                let (name, id) = parser::country_code(line).expect("Invalid country code line");
+            ParserState::CountryCode(m, ref mut current, counts) => {
+                let (name, id) = parser::country_code(line)
+                    .map_err(|e| Rejection::new("invalid country code line", line, &[e]))?;
                 if let Some(cv) = current {
                     m.entry(cv.id, &quote!(#cv).to_string());
+                    counts.add(cv);
                 }
 
                 // Set our new class as the current class.
@@ -404,10 +756,12 @@ impl ParserState {
                     name: name.into(),
                 });
             }
-            ParserState::TerminalType(m, ref mut current) => {
-                let (name, id) = parser::terminal_type(line).expect("Invalid terminal type line");
+            ParserState::TerminalType(m, ref mut current, counts) => {
+                let (name, id) = parser::terminal_type(line)
+                    .map_err(|e| Rejection::new("invalid terminal type line", line, &[e]))?;
                 if let Some(cv) = current {
                     m.entry(cv.id, &quote!(#cv).to_string());
+                    counts.add(cv);
                 }
 
                 // Set our new class as the current class.
@@ -417,43 +771,110 @@ impl ParserState {
                 });
             }
         }
+
+        Ok(())
     }
 
     /// Emit the prologue and map to the output file.
     ///
     /// Should only be called once per state, used before switching.
     fn finalize(&mut self, output: &mut impl Write) {
+        // Sections disabled by features are parsed as usual, but never written out
+        if !self.enabled() {
+            return;
+        }
+
         // Emit any pending contained within
         self.emit();
 
         // Write the prologue
         writeln!(output, "{}", self.prologue_str()).unwrap();
 
-        // And the map itself
+        // And the map itself, collecting the section's totals along the way
+        let mut totals = Vec::new();
         match self {
-            ParserState::Vendors(m, _, _) => {
+            ParserState::Vendors(m, _, _, dm, counts, _) => {
+                writeln!(output, "{};", m.build()).unwrap();
+                writeln!(output, "{}", DEVICE_PROLOGUE).unwrap();
+                writeln!(output, "{};", dm.build()).unwrap();
+                totals.push(("VENDOR_COUNT", "vendors", counts.parents));
+                totals.push(("DEVICE_COUNT", "devices", counts.children));
+                totals.push(("INTERFACE_COUNT", "interfaces", counts.grandchildren));
+            }
+            ParserState::Classes(m, _, _, sm, pm, counts) => {
+                writeln!(output, "{};", m.build()).unwrap();
+                writeln!(output, "{}", SUBCLASS_PROLOGUE).unwrap();
+                writeln!(output, "{};", sm.build()).unwrap();
+                writeln!(output, "{}", PROTOCOL_PROLOGUE).unwrap();
+                writeln!(output, "{};", pm.build()).unwrap();
+                totals.push(("CLASS_COUNT", "classes", counts.parents));
+                totals.push(("SUBCLASS_COUNT", "subclasses", counts.children));
+                totals.push(("PROTOCOL_COUNT", "protocols", counts.grandchildren));
+            }
+            ParserState::AtType(m, _, counts) => {
+                writeln!(output, "{};", m.build()).unwrap();
+                totals.push((
+                    "AUDIO_TERMINAL_COUNT",
+                    "audio terminal types",
+                    counts.parents,
+                ));
+            }
+            ParserState::HidType(m, _, counts) => {
+                writeln!(output, "{};", m.build()).unwrap();
+                totals.push(("HID_COUNT", "HID descriptor types", counts.parents));
+            }
+            ParserState::RType(m, _, counts) => {
+                writeln!(output, "{};", m.build()).unwrap();
+                totals.push(("HID_ITEM_TYPE_COUNT", "HID item types", counts.parents));
+            }
+            ParserState::BiasType(m, _, counts) => {
                 writeln!(output, "{};", m.build()).unwrap();
+                totals.push(("BIAS_COUNT", "biases", counts.parents));
             }
-            ParserState::Classes(m, _, _) => {
+            ParserState::PhyType(m, _, counts) => {
                 writeln!(output, "{};", m.build()).unwrap();
+                totals.push(("PHY_COUNT", "physical descriptor items", counts.parents));
             }
-            ParserState::AtType(m, _) | ParserState::TerminalType(m, _) => {
+            ParserState::CountryCode(m, _, counts) => {
                 writeln!(output, "{};", m.build()).unwrap();
+                totals.push((
+                    "HID_COUNTRY_CODE_COUNT",
+                    "HID country codes",
+                    counts.parents,
+                ));
             }
-            ParserState::HidType(m, _)
-            | ParserState::RType(m, _)
-            | ParserState::BiasType(m, _)
-            | ParserState::CountryCode(m, _)
-            | ParserState::PhyType(m, _) => {
+            ParserState::TerminalType(m, _, counts) => {
                 writeln!(output, "{};", m.build()).unwrap();
+                totals.push((
+                    "VIDEO_TERMINAL_COUNT",
+                    "video terminal types",
+                    counts.parents,
+                ));
             }
-            ParserState::HutType(m, _) => {
+            ParserState::HutType(m, _, counts) => {
                 writeln!(output, "{};", m.build()).unwrap();
+                totals.push(("HID_USAGE_PAGE_COUNT", "HID usage pages", counts.parents));
+                totals.push(("HID_USAGE_COUNT", "HID usages", counts.children));
             }
-            ParserState::Lang(m, _) => {
+            ParserState::Lang(m, _, counts) => {
                 writeln!(output, "{};", m.build()).unwrap();
+                totals.push(("LANGUAGE_COUNT", "languages", counts.parents));
+                totals.push(("DIALECT_COUNT", "dialects", counts.children));
             }
         }
+
+        for &(name, kind, count) in &totals {
+            write_count(output, name, kind, count);
+        }
+
+        // Lets maintainers check that a database update wasn't truncated by parse failures
+        if env::var_os("USB_IDS_VERBOSE").is_some() {
+            let totals: Vec<_> = totals
+                .iter()
+                .map(|(_, kind, count)| format!("{} {}", count, kind))
+                .collect();
+            println!("cargo:warning=Parsed {}", totals.join(", "));
+        }
     }
 
     /// Return the next state for the current state based on the standard ordering of the file
@@ -463,166 +884,316 @@ impl ParserState {
     fn next(&mut self, output: &mut impl Write) -> Option<ParserState> {
         self.finalize(output);
         match self {
-            ParserState::Vendors(_, _, _) => {
-                Some(ParserState::Classes(Map::<u8>::new(), None, 0u8))
-            }
-            ParserState::Classes(_, _, _) => Some(ParserState::AtType(Map::<u16>::new(), None)),
-            ParserState::AtType(_, _) => Some(ParserState::HidType(Map::<u8>::new(), None)),
-            ParserState::HidType(_, _) => Some(ParserState::RType(Map::<u8>::new(), None)),
-            ParserState::RType(_, _) => Some(ParserState::BiasType(Map::<u8>::new(), None)),
-            ParserState::BiasType(_, _) => Some(ParserState::PhyType(Map::<u8>::new(), None)),
-            ParserState::PhyType(_, _) => Some(ParserState::HutType(Map::<u8>::new(), None)),
-            ParserState::HutType(_, _) => Some(ParserState::Lang(Map::<u16>::new(), None)),
-            ParserState::Lang(_, _) => Some(ParserState::CountryCode(Map::<u8>::new(), None)),
-            ParserState::CountryCode(_, _) => {
-                Some(ParserState::TerminalType(Map::<u16>::new(), None))
-            }
-            ParserState::TerminalType(_, _) => None,
+            ParserState::Vendors(_, _, _, _, _, _) => Some(ParserState::new_classes()),
+            ParserState::Classes(_, _, _, _, _, _) => Some(ParserState::AtType(
+                OrderedMap::<u16>::new(),
+                None,
+                Counts::default(),
+            )),
+            ParserState::AtType(_, _, _) => Some(ParserState::HidType(
+                OrderedMap::<u8>::new(),
+                None,
+                Counts::default(),
+            )),
+            ParserState::HidType(_, _, _) => Some(ParserState::RType(
+                OrderedMap::<u8>::new(),
+                None,
+                Counts::default(),
+            )),
+            ParserState::RType(_, _, _) => Some(ParserState::BiasType(
+                OrderedMap::<u8>::new(),
+                None,
+                Counts::default(),
+            )),
+            ParserState::BiasType(_, _, _) => Some(ParserState::PhyType(
+                OrderedMap::<u8>::new(),
+                None,
+                Counts::default(),
+            )),
+            ParserState::PhyType(_, _, _) => Some(ParserState::HutType(
+                OrderedMap::<u8>::new(),
+                None,
+                Counts::default(),
+            )),
+            ParserState::HutType(_, _, _) => Some(ParserState::Lang(
+                OrderedMap::<u16>::new(),
+                None,
+                Counts::default(),
+            )),
+            ParserState::Lang(_, _, _) => Some(ParserState::CountryCode(
+                OrderedMap::<u8>::new(),
+                None,
+                Counts::default(),
+            )),
+            ParserState::CountryCode(_, _, _) => Some(ParserState::TerminalType(
+                OrderedMap::<u16>::new(),
+                None,
+                Counts::default(),
+            )),
+            ParserState::TerminalType(_, _, _) => None,
         }
     }
 }
 
-#[allow(clippy::redundant_field_names)]
-fn main() {
-    let out_dir = env::var_os("OUT_DIR").unwrap();
-    let src_path = Path::new("src/usb.ids");
-    let dest_path = Path::new(&out_dir).join("usb_ids.cg.rs");
-    let input = {
-        let f = fs::File::open(src_path).unwrap();
-        BufReader::new(f)
-    };
-    let mut output = {
-        let f = fs::File::create(dest_path).unwrap();
-        BufWriter::new(f)
-    };
-
-    // Parser state machine starts with vendors (first in file)
-    let mut parser_state: ParserState = ParserState::Vendors(Map::<u16>::new(), None, 0u16);
+/// Write a documented `pub const` holding the number of entities of a given kind
+fn write_count(output: &mut impl Write, name: &str, kind: &str, count: usize) {
+    writeln!(output, "/// The number of {} in the USB database.", kind).unwrap();
+    writeln!(output, "pub const {}: usize = {};", name, count).unwrap();
+}
 
-    #[allow(clippy::lines_filter_map_ok)]
-    for line in input.lines().flatten() {
-        // Check for a state change based on the header comments
-        if let Some(next_state) = parser_state.next_from_header(&line, &mut output) {
-            parser_state = next_state;
+/// The database that ships with this crate
+const VENDORED_DATABASE: &str = "src/usb.ids";
+
+/// Where the `fetch-db` feature downloads the latest database from
+#[cfg(feature = "fetch-db")]
+const DATABASE_URL: &str = "https://www.linux-usb.org/usb.ids";
+
+/// Downloads the latest database into `out_dir`, returning its path.
+///
+/// The response's `ETag` is cached next to the database, so an unchanged database
+/// isn't downloaded again.
+#[cfg(feature = "fetch-db")]
+fn fetch_database(out_dir: &Path) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    use std::io::Read;
+    use std::time::Duration;
+
+    let db_path = out_dir.join("usb.ids");
+    let etag_path = out_dir.join("usb.ids.etag");
+
+    let agent = ureq::AgentBuilder::new()
+        .timeout(Duration::from_secs(30))
+        .build();
+    let mut request = agent.get(DATABASE_URL);
+    if db_path.exists() {
+        if let Ok(etag) = fs::read_to_string(&etag_path) {
+            request = request.set("If-None-Match", etag.trim());
         }
+    }
 
-        // Process line for current parser
-        parser_state.process(&line);
+    let response = request.call()?;
+    if response.status() == 304 {
+        return Ok(db_path);
     }
 
-    // Last call for last parser in file
-    parser_state.finalize(&mut output);
+    let etag = response.header("ETag").map(str::to_string);
+    let mut body = Vec::new();
+    response.into_reader().read_to_end(&mut body)?;
 
-    println!("cargo:rerun-if-changed=build.rs");
-    println!("cargo:rerun-if-changed=src/usb.ids");
+    // Drop the old ETag first, so that a failed write can't leave it pointing at a
+    // database it doesn't describe
+    let _ = fs::remove_file(&etag_path);
+    fs::write(&db_path, body)?;
+    if let Some(etag) = etag {
+        fs::write(&etag_path, etag)?;
+    }
+
+    Ok(db_path)
 }
 
-mod parser {
-    use std::num::ParseIntError;
+/// The database to use when `USB_IDS_PATH` isn't set
+#[cfg(feature = "fetch-db")]
+fn default_database(out_dir: &Path) -> PathBuf {
+    fetch_database(out_dir).unwrap_or_else(|e| {
+        println!(
+            "cargo:warning=couldn't download the USB database, using the vendored one: {}",
+            e
+        );
+        PathBuf::from(VENDORED_DATABASE)
+    })
+}
 
-    use nom::bytes::complete::{tag, take};
-    use nom::character::complete::{hex_digit1, tab};
-    use nom::combinator::{all_consuming, map_parser, map_res};
-    use nom::sequence::{delimited, terminated};
-    use nom::IResult;
+/// The database to use when `USB_IDS_PATH` isn't set
+#[cfg(not(feature = "fetch-db"))]
+fn default_database(_out_dir: &Path) -> PathBuf {
+    PathBuf::from(VENDORED_DATABASE)
+}
 
-    fn id<T, F>(size: usize, from_str_radix: F) -> impl Fn(&str) -> IResult<&str, T>
-    where
-        F: Fn(&str, u32) -> Result<T, ParseIntError>,
-    {
-        move |input| {
-            map_res(map_parser(take(size), all_consuming(hex_digit1)), |input| {
-                from_str_radix(input, 16)
-            })(input)
-        }
-    }
+fn main() {
+    let out_dir = env::var_os("OUT_DIR").unwrap();
+    // Users can supply their own database, e.g. a locally patched or newer one
+    let src_path = match env::var_os("USB_IDS_PATH") {
+        Some(path) => PathBuf::from(path),
+        None => default_database(Path::new(&out_dir)),
+    };
+    // ...and augment it with their own entries, e.g. for unreleased devices
+    let extra_path = env::var_os("USB_IDS_EXTRA_PATH").map(PathBuf::from);
+    let dest_path = Path::new(&out_dir).join("usb_ids.cg.rs");
+    let cache_path = Path::new(&out_dir).join("usb_ids.hash");
 
-    pub fn vendor(input: &str) -> IResult<&str, u16> {
-        let id = id(4, u16::from_str_radix);
-        terminated(id, tag("  "))(input)
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=USB_IDS_PATH");
+    println!("cargo:rerun-if-env-changed=USB_IDS_VERBOSE");
+    println!("cargo:rerun-if-changed={}", src_path.display());
+    println!("cargo:rerun-if-env-changed=USB_IDS_EXTRA_PATH");
+    if let Some(extra_path) = &extra_path {
+        println!("cargo:rerun-if-changed={}", extra_path.display());
     }
 
-    pub fn device(input: &str) -> IResult<&str, u16> {
-        let id = id(4, u16::from_str_radix);
-        delimited(tab, id, tag("  "))(input)
-    }
+    // Each file is read once, for both the hash and the parser
+    let src = read_database(&src_path);
+    let extra = extra_path
+        .as_deref()
+        .map(|path| (path, read_database(path)));
 
-    pub fn interface(input: &str) -> IResult<&str, u8> {
-        let id = id(2, u8::from_str_radix);
-        delimited(tag("\t\t"), id, tag("  "))(input)
+    let mut hasher = Sha256::new();
+    hasher.update(&src);
+    if let Some((_, extra)) = &extra {
+        hasher.update(extra);
     }
-
-    pub fn class(input: &str) -> IResult<&str, u8> {
-        let id = id(2, u8::from_str_radix);
-        delimited(tag("C "), id, tag("  "))(input)
+    let hash = hex(&hasher.finalize());
+
+    // Cargo reruns us whenever the database's mtime changes, but regenerating the maps is
+    // slow, so skip it when nothing that goes into them has changed. A verbose build always
+    // regenerates, since it's asking for the counts to be reported.
+    let cache_key = cache_key(&hash);
+    if env::var_os("USB_IDS_VERBOSE").is_none()
+        && dest_path.exists()
+        && fs::read_to_string(&cache_path).ok().as_deref() == Some(cache_key.as_str())
+    {
+        return;
     }
+    // Don't trust a stale key if we're interrupted partway through regenerating
+    let _ = fs::remove_file(&cache_path);
 
-    pub fn sub_class(input: &str) -> IResult<&str, u8> {
-        let id = id(2, u8::from_str_radix);
-        delimited(tab, id, tag("  "))(input)
-    }
+    let input = match &extra {
+        Some((extra_path, extra)) => merge::merge(lines(&src_path, &src), lines(extra_path, extra)),
+        None => lines(&src_path, &src),
+    };
+    let mut output = {
+        let f = fs::File::create(dest_path).unwrap();
+        BufWriter::new(f)
+    };
 
-    pub fn protocol(input: &str) -> IResult<&str, u8> {
-        let id = id(2, u8::from_str_radix);
-        delimited(tag("\t\t"), id, tag("  "))(input)
-    }
+    // Parser state machine starts with vendors (first in file)
+    let mut parser_state = ParserState::new_vendors();
+    let mut version = None;
+    let mut date = None;
+
+    let mut skipped = 0;
+
+    for (path, number, line) in input {
+        // The database's provenance is recorded in the header comments
+        if let Ok((v, _)) = parser::version(&line) {
+            version = Some(v.trim().to_string());
+        } else if let Ok((d, _)) = parser::date(&line) {
+            date = Some(d.trim().to_string());
+        }
 
-    pub fn audio_terminal_type(input: &str) -> IResult<&str, u16> {
-        let id = id(4, u16::from_str_radix);
-        delimited(tag("AT "), id, tag("  "))(input)
-    }
+        // Check for a state change based on the header comments
+        if let Some(next_state) = parser_state.next_from_header(&line, &mut output) {
+            parser_state = next_state;
+        }
 
-    pub fn hid_type(input: &str) -> IResult<&str, u8> {
-        let id = id(2, u8::from_str_radix);
-        delimited(tag("HID "), id, tag("  "))(input)
+        // Process line for current parser, skipping (but reporting) any it can't parse;
+        // the rest of the section is still usable
+        if let Err(rejection) = parser_state.process(&line) {
+            println!(
+                "cargo:warning=Skipping unrecognized line in {}: {:?} (line {}, column {} of {}: {})",
+                parser_state.section_name(),
+                line,
+                number,
+                rejection.offset + 1,
+                path.display(),
+                rejection.reason
+            );
+            skipped += 1;
+        }
     }
 
-    pub fn hid_item_type(input: &str) -> IResult<&str, u8> {
-        let id = id(2, u8::from_str_radix);
-        delimited(tag("R "), id, tag("  "))(input)
+    if skipped > 0 {
+        println!("cargo:warning=Skipped {} unparseable line(s)", skipped);
     }
 
-    pub fn bias_type(input: &str) -> IResult<&str, u8> {
-        let id = id(1, u8::from_str_radix);
-        delimited(tag("BIAS "), id, tag("  "))(input)
-    }
+    // Last call for last parser in file
+    parser_state.finalize(&mut output);
 
-    pub fn phy_type(input: &str) -> IResult<&str, u8> {
-        let id = id(2, u8::from_str_radix);
-        delimited(tag("PHY "), id, tag("  "))(input)
-    }
+    // Custom databases don't necessarily have the usual header, and it's only informational
+    let version = version.unwrap_or_else(|| {
+        println!("cargo:warning=No # Version: line in the database header");
+        String::new()
+    });
+    let date = date.unwrap_or_else(|| {
+        println!("cargo:warning=No # Date: line in the database header");
+        String::new()
+    });
+    writeln!(
+        output,
+        "/// The version of the embedded USB database, e.g. `2025.01.14`, or empty if its \
+         header doesn't record one."
+    )
+    .unwrap();
+    writeln!(output, "pub const DATABASE_VERSION: &str = {:?};", version).unwrap();
+    writeln!(
+        output,
+        "/// The date of the embedded USB database, e.g. `2025-01-14 20:34:02`, or empty if \
+         its header doesn't record one."
+    )
+    .unwrap();
+    writeln!(output, "pub const DATABASE_DATE: &str = {:?};", date).unwrap();
+
+    writeln!(
+        output,
+        "/// The SHA-256 digest of the embedded USB database, as lowercase hex."
+    )
+    .unwrap();
+    writeln!(output, "pub const DATABASE_HASH: &str = {:?};", hash).unwrap();
+
+    output.flush().unwrap();
+    fs::write(&cache_path, cache_key).unwrap();
+}
 
-    pub fn hut_type(input: &str) -> IResult<&str, u8> {
-        let id = id(2, u8::from_str_radix);
-        delimited(tag("HUT "), id, tag("  "))(input)
-    }
+fn hex(digest: &[u8]) -> String {
+    digest.iter().map(|b| format!("{:02x}", b)).collect()
+}
 
-    pub fn hid_usage_name(input: &str) -> IResult<&str, u16> {
-        let id = id(3, u16::from_str_radix);
-        delimited(tab, id, tag("  "))(input)
+/// Identifies everything the generated code depends on: the database (by its hash), the
+/// build script itself, and the crate features that enable or disable sections
+fn cache_key(database_hash: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(database_hash);
+    for source in &[
+        include_str!("build.rs"),
+        include_str!("build/parser.rs"),
+        include_str!("build/merge.rs"),
+    ] {
+        hasher.update(source);
     }
 
-    pub fn language(input: &str) -> IResult<&str, u16> {
-        let id = id(4, u16::from_str_radix);
-        delimited(tag("L "), id, tag("  "))(input)
+    let mut features: Vec<_> = env::vars()
+        .map(|(name, _)| name)
+        .filter(|name| name.starts_with("CARGO_FEATURE_"))
+        .collect();
+    features.sort();
+    for feature in features {
+        hasher.update(feature);
+        hasher.update("\n");
     }
 
-    pub fn dialect(input: &str) -> IResult<&str, u8> {
-        let id = id(2, u8::from_str_radix);
-        delimited(tab, id, tag("  "))(input)
-    }
+    hex(&hasher.finalize())
+}
 
-    pub fn country_code(input: &str) -> IResult<&str, u8> {
-        let id = id(2, u8::from_str_radix);
-        delimited(tag("HCC "), id, tag("  "))(input)
-    }
+/// Reads a database file, failing the build with a clear error if it can't be read
+fn read_database(path: &Path) -> Vec<u8> {
+    fs::read(path)
+        .unwrap_or_else(|e| panic!("couldn't read USB database {}: {}", path.display(), e))
+}
 
-    pub fn terminal_type(input: &str) -> IResult<&str, u16> {
-        let id = id(4, u16::from_str_radix);
-        delimited(tag("VT "), id, tag("  "))(input)
-    }
+/// Splits a database file into lines, keeping track of where each came from
+fn lines<'a>(path: &'a Path, contents: &[u8]) -> Vec<merge::Line<'a>> {
+    String::from_utf8_lossy(contents)
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (path, index + 1, line.to_string()))
+        .collect()
 }
 
+// The parser lives in its own file so that the fuzz targets can share it
+#[path = "build/parser.rs"]
+mod parser;
+
+#[path = "build/merge.rs"]
+mod merge;
+
 impl quote::ToTokens for CgVendor {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let CgVendor {
@@ -631,11 +1202,7 @@ impl quote::ToTokens for CgVendor {
             devices,
         } = self;
 
-        let devices = devices.iter().map(|CgDevice { id, name, interfaces }| {
-            quote!{
-                Device { vendor_id: #vendor_id, id: #id, name: #name, interfaces: &[#(#interfaces),*] }
-            }
-        });
+        let devices = devices.iter().map(|device| device.to_tokens(*vendor_id));
         tokens.extend(quote! {
             Vendor { id: #vendor_id, name: #name, devices: &[#(#devices),*] }
         });
@@ -650,22 +1217,49 @@ impl quote::ToTokens for CgClass {
             sub_classes,
         } = self;
 
-        let sub_classes = sub_classes.iter().map(|CgSubClass { id, name, children }| {
+        let sub_classes = sub_classes
+            .iter()
+            .map(|sub_class| sub_class.to_tokens(*class_id));
+        tokens.extend(quote! {
+            Class { id: #class_id, name: #name, sub_classes: &[#(#sub_classes),*] }
+        });
+    }
+}
+
+impl quote::ToTokens for CgHut {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let CgParentType {
+            id: page_id,
+            name,
+            children,
+        } = self;
+
+        let usages = children.iter().map(|CgHidUsage { id, name }| {
             quote! {
-                SubClass { class_id: #class_id, id: #id, name: #name, protocols: &[#(#children),*] }
+                HidUsage { page_id: #page_id, id: #id, name: #name }
             }
         });
         tokens.extend(quote! {
-            Class { id: #class_id, name: #name, sub_classes: &[#(#sub_classes),*] }
+            UsbIdWithChildren { id: #page_id, name: #name, children: &[#(#usages),*] }
         });
     }
 }
 
-impl<T: quote::ToTokens, C: quote::ToTokens> quote::ToTokens for CgParentType<T, C> {
+impl quote::ToTokens for CgLang {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let CgParentType { id, name, children } = self;
+        let CgParentType {
+            id: language_id,
+            name,
+            children,
+        } = self;
+
+        let dialects = children.iter().map(|CgDialect { id, name }| {
+            quote! {
+                Dialect { language_id: #language_id, id: #id, name: #name }
+            }
+        });
         tokens.extend(quote! {
-            UsbIdWithChildren { id: #id, name: #name, children: &[#(#children),*] }
+            UsbIdWithChildren { id: #language_id, name: #name, children: &[#(#dialects),*] }
         });
     }
 }
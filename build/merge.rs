@@ -0,0 +1,178 @@
+//! Merges a user-supplied file of extra entries (`USB_IDS_EXTRA_PATH`) into the database.
+//!
+//! The merge works on the database's lines rather than its parsed entries, since the parser
+//! emits each entry as soon as it's complete. Entries are matched by their ID, including any
+//! section prefix (e.g. `C 03` or `HUT 07`), so the extra file doesn't need section headers:
+//! a new entry is placed among the existing entries of the same kind.
+
+use std::path::Path;
+
+/// A line of a database file, along with the file and (1-based) line number it came from
+pub type Line<'a> = (&'a Path, usize, String);
+
+/// A database entry, along with its (indented) children
+struct Entry<'a> {
+    line: Line<'a>,
+    children: Vec<Entry<'a>>,
+}
+
+enum Item<'a> {
+    /// A comment, blank line, or line that doesn't belong to any entry; passed through as is
+    Other(Line<'a>),
+    Entry(Entry<'a>),
+}
+
+/// The entry's ID, e.g. `1d6b` or `c 03`, or `0002` for one of a vendor's devices;
+/// lowercased, since the parser accepts hex IDs in either case
+fn key(entry: &Entry) -> String {
+    let text = entry.line.2.trim_start_matches('\t');
+    let id = text.split("  ").next().unwrap_or(text).trim_end();
+    id.to_ascii_lowercase()
+}
+
+/// The kind of a top-level entry, i.e. its section prefix, which is empty for vendors
+fn kind(key: &str) -> &str {
+    key.split_once(' ').map_or("", |(kind, _)| kind)
+}
+
+/// Insert `entry` among `entries` (all of the same kind), in ID order if they're sorted
+fn insert_sorted<'a>(entries: &mut Vec<Entry<'a>>, entry: Entry<'a>) {
+    let entry_key = key(&entry);
+    match entries.iter().position(|e| key(e) > entry_key) {
+        Some(index) => entries.insert(index, entry),
+        None => entries.push(entry),
+    }
+}
+
+fn is_comment(text: &str) -> bool {
+    text.trim().is_empty() || text.starts_with('#')
+}
+
+/// Add `line` as a child (at the given depth) of the most recent entry, handing it back if
+/// there's no parent for it to belong to
+fn attach<'a>(items: &mut [Item<'a>], line: Line<'a>, depth: usize) -> Result<(), Line<'a>> {
+    let last = items.iter_mut().rev().find_map(|item| match item {
+        Item::Entry(entry) => Some(entry),
+        Item::Other(_) => None,
+    });
+    let mut parent = match last {
+        Some(parent) => parent,
+        None => return Err(line),
+    };
+
+    for _ in 1..depth {
+        parent = match parent.children.last_mut() {
+            Some(child) => child,
+            None => return Err(line),
+        };
+    }
+
+    parent.children.push(Entry {
+        line,
+        children: Vec::new(),
+    });
+    Ok(())
+}
+
+fn parse(lines: Vec<Line>) -> Vec<Item> {
+    let mut items = Vec::new();
+    for line in lines {
+        let depth = line.2.len() - line.2.trim_start_matches('\t').len();
+        if is_comment(&line.2) {
+            items.push(Item::Other(line));
+        } else if depth == 0 {
+            items.push(Item::Entry(Entry {
+                line,
+                children: Vec::new(),
+            }));
+        } else if let Err(line) = attach(&mut items, line, depth) {
+            items.push(Item::Other(line));
+        }
+    }
+    items
+}
+
+/// Overwrite `into` with `from`, keeping any of `into`'s children that `from` doesn't have
+fn merge_entry<'a>(into: &mut Entry<'a>, from: Entry<'a>) {
+    into.line = from.line;
+    for child in from.children {
+        let child_key = key(&child);
+        match into.children.iter_mut().find(|c| key(c) == child_key) {
+            Some(existing) => merge_entry(existing, child),
+            None => insert_sorted(&mut into.children, child),
+        }
+    }
+}
+
+fn flatten<'a>(entry: Entry<'a>, lines: &mut Vec<Line<'a>>) {
+    lines.push(entry.line);
+    for child in entry.children {
+        flatten(child, lines);
+    }
+}
+
+/// Merge the `extra` lines into the `main` ones: new entries are added, and existing ones
+/// are overwritten (along with their children, recursively)
+pub fn merge<'a>(main: Vec<Line<'a>>, extra: Vec<Line<'a>>) -> Vec<Line<'a>> {
+    let mut items = parse(main);
+
+    for item in parse(extra) {
+        let entry = match item {
+            Item::Entry(entry) => entry,
+            Item::Other((path, number, text)) => {
+                if !is_comment(&text) {
+                    println!(
+                        "cargo:warning=Skipping line outside of any entry: {:?} (line {} of {})",
+                        text,
+                        number,
+                        path.display()
+                    );
+                }
+                continue;
+            }
+        };
+
+        let entry_key = key(&entry);
+        let existing = items.iter_mut().find_map(|item| match item {
+            Item::Entry(e) if key(e) == entry_key => Some(e),
+            _ => None,
+        });
+        if let Some(existing) = existing {
+            merge_entry(existing, entry);
+            continue;
+        }
+
+        // Entries of each kind are contiguous and (usually) sorted by ID, so a new entry goes
+        // before the first one of its kind with a greater ID, or after the last of its kind
+        let entry_kind = kind(&entry_key);
+        let same_kind = |item: &Item| match item {
+            Item::Entry(e) => kind(&key(e)) == entry_kind,
+            Item::Other(_) => false,
+        };
+        let greater = items.iter().position(|item| match item {
+            Item::Entry(e) => same_kind(item) && key(e) > entry_key,
+            Item::Other(_) => false,
+        });
+        match greater.or_else(|| items.iter().rposition(same_kind).map(|index| index + 1)) {
+            Some(index) => items.insert(index, Item::Entry(entry)),
+            None => {
+                let (path, number, text) = &entry.line;
+                println!(
+                    "cargo:warning=Skipping entry with no matching section: {:?} (line {} of {})",
+                    text,
+                    number,
+                    path.display()
+                );
+            }
+        }
+    }
+
+    let mut lines = Vec::new();
+    for item in items {
+        match item {
+            Item::Other(line) => lines.push(line),
+            Item::Entry(entry) => flatten(entry, &mut lines),
+        }
+    }
+    lines
+}
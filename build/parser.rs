@@ -0,0 +1,122 @@
+//! The line parsers for the USB ID database, shared between the build script and the
+//! fuzz targets.
+
+use std::num::ParseIntError;
+
+use nom::bytes::complete::{tag, take, take_while1};
+use nom::character::complete::{hex_digit1, satisfy, space1, tab};
+use nom::combinator::{all_consuming, map_parser, map_res, peek};
+use nom::sequence::{delimited, terminated};
+use nom::IResult;
+
+fn id<T, F>(size: usize, from_str_radix: F) -> impl Fn(&str) -> IResult<&str, T>
+where
+    F: Fn(&str, u32) -> Result<T, ParseIntError>,
+{
+    move |input| {
+        map_res(map_parser(take(size), all_consuming(hex_digit1)), |input| {
+            from_str_radix(input, 16)
+        })(input)
+    }
+}
+
+pub fn version(input: &str) -> IResult<&str, &str> {
+    terminated(tag("# Version:"), space1)(input)
+}
+
+pub fn date(input: &str) -> IResult<&str, &str> {
+    terminated(tag("# Date:"), space1)(input)
+}
+
+/// Matches a section header comment, e.g. `# AT terminal_type  terminal_type_name`,
+/// returning its tag (`AT`)
+pub fn section_header(input: &str) -> IResult<&str, &str> {
+    let section_tag = take_while1(|c: char| c.is_ascii_uppercase());
+    let syntax = peek(satisfy(|c| c.is_ascii_lowercase()));
+    terminated(delimited(tag("# "), section_tag, tag(" ")), syntax)(input)
+}
+
+pub fn vendor(input: &str) -> IResult<&str, u16> {
+    let id = id(4, u16::from_str_radix);
+    terminated(id, tag("  "))(input)
+}
+
+pub fn device(input: &str) -> IResult<&str, u16> {
+    let id = id(4, u16::from_str_radix);
+    delimited(tab, id, tag("  "))(input)
+}
+
+pub fn interface(input: &str) -> IResult<&str, u8> {
+    let id = id(2, u8::from_str_radix);
+    delimited(tag("\t\t"), id, tag("  "))(input)
+}
+
+pub fn class(input: &str) -> IResult<&str, u8> {
+    let id = id(2, u8::from_str_radix);
+    delimited(tag("C "), id, tag("  "))(input)
+}
+
+pub fn sub_class(input: &str) -> IResult<&str, u8> {
+    let id = id(2, u8::from_str_radix);
+    delimited(tab, id, tag("  "))(input)
+}
+
+pub fn protocol(input: &str) -> IResult<&str, u8> {
+    let id = id(2, u8::from_str_radix);
+    delimited(tag("\t\t"), id, tag("  "))(input)
+}
+
+pub fn audio_terminal_type(input: &str) -> IResult<&str, u16> {
+    let id = id(4, u16::from_str_radix);
+    delimited(tag("AT "), id, tag("  "))(input)
+}
+
+pub fn hid_type(input: &str) -> IResult<&str, u8> {
+    let id = id(2, u8::from_str_radix);
+    delimited(tag("HID "), id, tag("  "))(input)
+}
+
+pub fn hid_item_type(input: &str) -> IResult<&str, u8> {
+    let id = id(2, u8::from_str_radix);
+    delimited(tag("R "), id, tag("  "))(input)
+}
+
+pub fn bias_type(input: &str) -> IResult<&str, u8> {
+    let id = id(1, u8::from_str_radix);
+    delimited(tag("BIAS "), id, tag("  "))(input)
+}
+
+pub fn phy_type(input: &str) -> IResult<&str, u8> {
+    let id = id(2, u8::from_str_radix);
+    delimited(tag("PHY "), id, tag("  "))(input)
+}
+
+pub fn hut_type(input: &str) -> IResult<&str, u8> {
+    let id = id(2, u8::from_str_radix);
+    delimited(tag("HUT "), id, tag("  "))(input)
+}
+
+pub fn hid_usage_name(input: &str) -> IResult<&str, u16> {
+    let id = id(3, u16::from_str_radix);
+    delimited(tab, id, tag("  "))(input)
+}
+
+pub fn language(input: &str) -> IResult<&str, u16> {
+    let id = id(4, u16::from_str_radix);
+    delimited(tag("L "), id, tag("  "))(input)
+}
+
+pub fn dialect(input: &str) -> IResult<&str, u8> {
+    let id = id(2, u8::from_str_radix);
+    delimited(tab, id, tag("  "))(input)
+}
+
+pub fn country_code(input: &str) -> IResult<&str, u8> {
+    let id = id(2, u8::from_str_radix);
+    delimited(tag("HCC "), id, tag("  "))(input)
+}
+
+pub fn terminal_type(input: &str) -> IResult<&str, u16> {
+    let id = id(4, u16::from_str_radix);
+    delimited(tag("VT "), id, tag("  "))(input)
+}
@@ -0,0 +1,33 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+#[path = "../../build/parser.rs"]
+mod parser;
+
+fuzz_target!(|data: &[u8]| {
+    // The build script only ever hands the parsers lines of UTF-8.
+    if let Ok(line) = std::str::from_utf8(data) {
+        // None of the parsers should panic, no matter the input.
+        let _ = parser::version(line);
+        let _ = parser::date(line);
+        let _ = parser::section_header(line);
+        let _ = parser::vendor(line);
+        let _ = parser::device(line);
+        let _ = parser::interface(line);
+        let _ = parser::class(line);
+        let _ = parser::sub_class(line);
+        let _ = parser::protocol(line);
+        let _ = parser::audio_terminal_type(line);
+        let _ = parser::hid_type(line);
+        let _ = parser::hid_item_type(line);
+        let _ = parser::bias_type(line);
+        let _ = parser::phy_type(line);
+        let _ = parser::hut_type(line);
+        let _ = parser::hid_usage_name(line);
+        let _ = parser::language(line);
+        let _ = parser::dialect(line);
+        let _ = parser::country_code(line);
+        let _ = parser::terminal_type(line);
+    }
+});
@@ -0,0 +1,239 @@
+//! [`defmt::Format`] impls for the public types, mirroring their `Display` impls.
+//!
+//! These can't be derived, since `defmt` can't derive formatting for `&'static str` fields.
+
+use defmt::{write, Format, Formatter};
+
+use crate::*;
+
+impl<const ID: u8> Format for UsbId<ID, u8> {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{=str} ({=u8:#04x})", self.name, self.id)
+    }
+}
+
+impl<const ID: u8> Format for UsbId<ID, u16> {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{=str} ({=u16:#06x})", self.name, self.id)
+    }
+}
+
+impl<C: 'static> Format for UsbIdWithChildren<u8, C> {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{=str} ({=u8:#04x})", self.name, self.id)
+    }
+}
+
+impl<C: 'static> Format for UsbIdWithChildren<u16, C> {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{=str} ({=u16:#06x})", self.name, self.id)
+    }
+}
+
+impl Format for Vendor {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{=str} ({=u16:#06x})", self.name, self.id)
+    }
+}
+
+impl Format for Device {
+    fn format(&self, f: Formatter<'_>) {
+        write!(
+            f,
+            "{=str} {=str} ({=u16:#06x}:{=u16:#06x})",
+            self.vendor().name,
+            self.name,
+            self.vendor_id,
+            self.id
+        )
+    }
+}
+
+impl Format for Interface {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{=str} ({=u8:#04x})", self.name, self.id)
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl Format for Class {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{=str} ({=u8:#04x})", self.name, self.id)
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl Format for SubClass {
+    fn format(&self, f: Formatter<'_>) {
+        write!(
+            f,
+            "{=str} / {=str} ({=u8:#04x}/{=u8:#04x})",
+            self.class().name,
+            self.name,
+            self.class_id,
+            self.id
+        )
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl Format for Protocol {
+    fn format(&self, f: Formatter<'_>) {
+        write!(
+            f,
+            "{=str} / {=str} / {=str} ({=u8:#04x}/{=u8:#04x}/{=u8:#04x})",
+            self.class().name,
+            self.subclass().name,
+            self.name,
+            self.class_id,
+            self.subclass_id,
+            self.id
+        )
+    }
+}
+
+#[cfg(not(feature = "no-hid"))]
+impl Format for HidUsage {
+    fn format(&self, f: Formatter<'_>) {
+        write!(
+            f,
+            "{=str} / {=str} ({=u8:#04x}/{=u16:#06x})",
+            self.page().name,
+            self.name,
+            self.page_id,
+            self.id
+        )
+    }
+}
+
+#[cfg(not(feature = "no-languages"))]
+impl Format for Dialect {
+    fn format(&self, f: Formatter<'_>) {
+        write!(
+            f,
+            "{=str} / {=str} ({=u16:#06x}/{=u8:#04x})",
+            self.language().name,
+            self.name,
+            self.language_id,
+            self.id
+        )
+    }
+}
+
+impl Format for UsbIdError {
+    fn format(&self, f: Formatter<'_>) {
+        match *self {
+            UsbIdError::VendorNotFound(vid) => {
+                write!(f, "no vendor {=u16:#06x} in the USB database", vid)
+            }
+            UsbIdError::DeviceNotFound { vid, pid } => {
+                write!(f, "vendor {=u16:#06x} has no device {=u16:#06x}", vid, pid)
+            }
+            UsbIdError::ClassNotFound(class_id) => {
+                write!(f, "no class {=u8:#04x} in the USB database", class_id)
+            }
+            UsbIdError::SubClassNotFound {
+                class_id,
+                subclass_id,
+            } => write!(
+                f,
+                "class {=u8:#04x} has no subclass {=u8:#04x}",
+                class_id, subclass_id
+            ),
+            UsbIdError::ProtocolNotFound {
+                class_id,
+                subclass_id,
+                protocol_id,
+            } => write!(
+                f,
+                "subclass {=u8:#04x}/{=u8:#04x} has no protocol {=u8:#04x}",
+                class_id, subclass_id, protocol_id
+            ),
+            UsbIdError::AudioTerminalNotFound(id) => {
+                write!(
+                    f,
+                    "no audio terminal type {=u16:#06x} in the USB database",
+                    id
+                )
+            }
+            UsbIdError::HidNotFound(id) => {
+                write!(
+                    f,
+                    "no HID descriptor type {=u8:#04x} in the USB database",
+                    id
+                )
+            }
+            UsbIdError::HidItemTypeNotFound(id) => {
+                write!(f, "no HID item type {=u8:#04x} in the USB database", id)
+            }
+            UsbIdError::BiasNotFound(id) => {
+                write!(f, "no bias {=u8:#04x} in the USB database", id)
+            }
+            UsbIdError::PhyNotFound(id) => {
+                write!(
+                    f,
+                    "no physical descriptor item {=u8:#04x} in the USB database",
+                    id
+                )
+            }
+            UsbIdError::HidUsagePageNotFound(id) => {
+                write!(f, "no HID usage page {=u8:#04x} in the USB database", id)
+            }
+            UsbIdError::LanguageNotFound(id) => {
+                write!(f, "no language {=u16:#06x} in the USB database", id)
+            }
+            UsbIdError::HidCountryCodeNotFound(id) => {
+                write!(f, "no HID country code {=u8:#04x} in the USB database", id)
+            }
+            UsbIdError::VideoTerminalNotFound(id) => {
+                write!(
+                    f,
+                    "no video terminal type {=u16:#06x} in the USB database",
+                    id
+                )
+            }
+        }
+    }
+}
+
+impl Format for ParseIdError {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "invalid USB ID, expected {=str}", self.expected)
+    }
+}
+
+impl Format for VendorId {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{=u16:#06x}", self.0)
+    }
+}
+
+impl Format for ProductId {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{=u16:#06x}", self.0)
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl Format for ClassId {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{=u8:#04x}", self.0)
+    }
+}
+
+impl Format for VidPid {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{=u16:04x}:{=u16:04x}", self.vid, self.pid)
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl Format for ClassTriple {
+    fn format(&self, f: Formatter<'_>) {
+        write!(
+            f,
+            "{=u8:02x}/{=u8:02x}/{=u8:02x}",
+            self.class_id, self.subclass_id, self.protocol_id
+        )
+    }
+}
@@ -7,6 +7,16 @@
 //!
 //! # Usage
 //!
+//! The most commonly used types, and the [`FromId`] trait, can be imported at once from
+//! the [`prelude`]:
+//!
+//! ```rust
+//! use usb_ids::prelude::*;
+//!
+//! let device = Device::from_vid_pid(0x1d6b, 0x0003).unwrap();
+//! assert_eq!(device.vendor(), Vendor::from_id(0x1d6b).unwrap());
+//! ```
+//!
 //! Iterating over all known vendors:
 //!
 //! ```rust
@@ -22,6 +32,8 @@
 //! Iterating over all known classes:
 //!
 //! ```rust
+//! # #[cfg(not(feature = "vendors-only"))]
+//! # {
 //! use usb_ids::Classes;
 //!
 //! for class in Classes::iter() {
@@ -33,20 +45,108 @@
 //!         }
 //!     }
 //! }
+//! # }
 //! ```
 //!
 //! See the individual documentation for each structure for more details.
 //!
+//! The number of entries in each table is known at compile time, and is available as a
+//! constant, e.g. for pre-allocating or for static assertions:
+//!
+//! ```rust
+//! const _: () = assert!(usb_ids::VENDOR_COUNT > 0);
+//!
+//! assert_eq!(usb_ids::DEVICE_COUNT, usb_ids::Devices::iter().count());
+//! ```
+//!
+//! # Reducing binary size
+//!
+//! The embedded database is large, so parts of it can be omitted (along with the types
+//! for browsing them) with any combination of these features:
+//!
+//! * `no-hid`: HID descriptor types, item types, usage pages, biases, physical descriptor
+//!   items, and country codes.
+//! * `no-audio`: audio terminal types.
+//! * `no-video`: video terminal types.
+//! * `no-languages`: languages and dialects.
+//! * `vendors-only`: everything except the vendor, device, and interface tables; implies
+//!   all of the above, and also drops classes.
+//!
+//! # Faster name lookups
+//!
+//! Lookups by exact name, like [`Vendor::from_name`], scan the whole table by default.
+//! The `name-index` feature (which requires `std`) makes them `O(1)` instead, at the cost
+//! of building a hash map per table the first time it's searched by name.
+//!
+//! # Using a custom database
+//!
+//! The vendored database can be replaced at build time by setting the `USB_IDS_PATH`
+//! environment variable to the path of another `usb.ids` file, e.g. a locally patched
+//! or newer one. Relative paths are resolved against this crate's root, so prefer an
+//! absolute path. If the file lacks the usual `# Version:` and `# Date:` header lines,
+//! the build warns and `DATABASE_VERSION` and `DATABASE_DATE` are empty.
+//!
+//! Alternatively, the `fetch-db` feature downloads the latest database from the
+//! repository whenever the build script runs, falling back to the vendored database (with
+//! a warning) if the download fails. `USB_IDS_PATH` takes precedence over `fetch-db`.
+//!
+//! To add to the database rather than replace it (e.g. with proprietary or unreleased
+//! devices), set `USB_IDS_EXTRA_PATH` to a file of extra entries in the same format. They're
+//! merged into the database by ID: new entries are added, and existing ones are
+//! overwritten, keeping any children the extra file doesn't mention. Section headers aren't
+//! needed, and `DATABASE_HASH` covers both files.
+//!
+//! Setting the `USB_IDS_VERBOSE` environment variable makes the build script report how
+//! many entries it parsed from each section (as Cargo warnings), which is a quick way to
+//! check that a new database wasn't silently truncated.
+//!
+//! # `no_std`
+//!
+//! All lookups are backed by static maps and never allocate, so this crate supports
+//! `no_std` targets when its default `std` feature is disabled. The `std` feature
+//! adds [`std::error::Error`] impls for the error types (and is required by the
+//! `name-index` and `regex` features). The `alloc` feature (implied by `std`) adds the
+//! APIs that return a `String` or `Vec`:
+//!
+//! * the sorted iterators, e.g. `Vendors::iter_sorted_by_name` and
+//!   `Vendor::devices_sorted_by_name`;
+//! * the formatting helpers: `describe_vid_pid`, `describe_class_triple`, the `full_path`
+//!   methods, `id_as_hex` and `Device::as_vid_pid_hex`;
+//! * `search_all` and its `SearchResults`.
+//!
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![warn(missing_docs)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::num::ParseIntError;
+use core::ops::Index;
+use core::str::FromStr;
+
 include!(concat!(env!("OUT_DIR"), "/usb_ids.cg.rs"));
 
+#[cfg(feature = "defmt")]
+mod defmt_impls;
+pub mod prelude;
+
 /// Represents a generic USB ID in the USB database.
 ///
 /// Not designed to be used directly; use one of the type aliases instead.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(
+    feature = "serde_hex_ids",
+    serde(bound(serialize = "T: core::fmt::LowerHex"))
+)]
 pub struct UsbId<const ID: u8, T> {
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
     id: T,
     name: &'static str,
 }
@@ -63,11 +163,59 @@ impl<const ID: u8, T: Copy> UsbId<ID, T> {
     }
 }
 
+impl<const ID: u8, T: Hash> Hash for UsbId<ID, T> {
+    /// Hashes only the type's ID, which uniquely identifies it within the USB database.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl<const ID: u8, T: fmt::LowerHex> fmt::Display for UsbId<ID, T> {
+    /// Formats the type as `name (id)`, e.g. `Microphone (0x0201)`.
+    ///
+    /// The ID is zero-padded to the natural width of its type.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = 2 + 2 * core::mem::size_of::<T>();
+        write!(f, "{} ({:#0width$x})", self.name, self.id, width = width)
+    }
+}
+
+impl<const ID: u8, T: fmt::LowerHex> fmt::LowerHex for UsbId<ID, T> {
+    /// Formats the type's ID as lowercase hex, zero-padded to the natural width of its
+    /// type, e.g. `070a`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = 2 * core::mem::size_of::<T>();
+        write!(f, "{:0width$x}", self.id, width = width)
+    }
+}
+
+impl<const ID: u8, T: fmt::UpperHex> fmt::UpperHex for UsbId<ID, T> {
+    /// Formats the type's ID as uppercase hex, zero-padded to the natural width of its
+    /// type, e.g. `070A`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = 2 * core::mem::size_of::<T>();
+        write!(f, "{:0width$X}", self.id, width = width)
+    }
+}
+
+impl<const ID: u8, T> AsRef<str> for UsbId<ID, T> {
+    /// Returns the type's name, like `name()`.
+    fn as_ref(&self) -> &str {
+        self.name
+    }
+}
+
 /// Represents a generic USB ID in the USB database with children IDs.
 ///
 /// Not designed to be used directly; use one of the type aliases instead.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(
+    feature = "serde_hex_ids",
+    serde(bound(serialize = "T: core::fmt::LowerHex, C: serde::Serialize"))
+)]
 pub struct UsbIdWithChildren<T: Copy, C: 'static> {
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
     id: T,
     name: &'static str,
     children: &'static [C],
@@ -85,614 +233,5530 @@ impl<T: Copy, C: 'static> UsbIdWithChildren<T, C> {
     }
 
     /// Returns an iterator over the type's children.
-    fn children(&self) -> impl Iterator<Item = &'static C> {
+    ///
+    /// This is what the type-specific accessors (like `HidUsagePage::usages`) use, and is
+    /// useful for code that's generic over any `UsbIdWithChildren`.
+    pub fn children(&self) -> impl ExactSizeIterator<Item = &'static C> {
         self.children.iter()
     }
-}
 
-/// An abstraction for iterating over all vendors in the USB database.
-pub struct Vendors;
-impl Vendors {
-    /// Returns an iterator over all vendors in the USB database.
-    pub fn iter() -> impl Iterator<Item = &'static Vendor> {
-        USB_IDS.values()
+    /// Returns the number of children belonging to the type.
+    ///
+    /// Unlike counting via the iterator, this is cheap (`O(1)`).
+    pub fn children_count(&self) -> usize {
+        self.children.len()
     }
-}
 
-/// An abstraction for iterating over all classes in the USB database.
-pub struct Classes;
-impl Classes {
-    /// Returns an iterator over all classes in the USB database.
-    pub fn iter() -> impl Iterator<Item = &'static Class> {
-        USB_CLASSES.values()
+    /// Returns `true` if the type has no children, e.g. a language without dialects.
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
     }
 }
 
-/// An abstraction for iterating over all languages in the USB database.
-///
-/// ```
-/// use usb_ids::Languages;
-/// for language in Languages::iter() {
-///    println!("language: {}", language.name());
-///    for dialect in language.dialects() {
-///         println!("\tdialect: {}", dialect.name());
-///    }
-/// }
-/// ```
-pub struct Languages;
-impl Languages {
-    /// Returns an iterator over all languages in the USB database.
-    pub fn iter() -> impl Iterator<Item = &'static Language> {
-        USB_LANGS.values()
+impl<T: Copy + Hash, C: 'static> Hash for UsbIdWithChildren<T, C> {
+    /// Hashes only the type's ID, which uniquely identifies it within the USB database.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
     }
 }
 
-/// An abstraction for iterating over all HID usage pages in the USB database.
-///
-/// ```
-/// use usb_ids::HidUsagePages;
-///
-/// for page in HidUsagePages::iter() {
-///     println!("page: {}", page.name());
-///     for usage in page.usages() {
-///         println!("\tusage: {}", usage.name());
-///     }
-/// }
-/// ```
-pub struct HidUsagePages;
-impl HidUsagePages {
-    /// Returns an iterator over all HID usage pages in the USB database.
-    pub fn iter() -> impl Iterator<Item = &'static HidUsagePage> {
-        USB_HUTS.values()
+impl<T: Copy + fmt::LowerHex, C: 'static> fmt::Display for UsbIdWithChildren<T, C> {
+    /// Formats the type as `name (id)`, e.g. `German (0x0007)`.
+    ///
+    /// The ID is zero-padded to the natural width of its type.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = 2 + 2 * core::mem::size_of::<T>();
+        write!(f, "{} ({:#0width$x})", self.name, self.id, width = width)
     }
 }
 
-/// Represents a USB device vendor in the USB database.
-///
-/// Every device vendor has a vendor ID, a pretty name, and a
-/// list of associated [`Device`]s.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct Vendor {
-    id: u16,
-    name: &'static str,
-    devices: &'static [Device],
+impl<T: Copy + fmt::LowerHex, C: 'static> fmt::LowerHex for UsbIdWithChildren<T, C> {
+    /// Formats the type's ID as lowercase hex, zero-padded to the natural width of its
+    /// type, e.g. `000c`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = 2 * core::mem::size_of::<T>();
+        write!(f, "{:0width$x}", self.id, width = width)
+    }
 }
 
-impl Vendor {
-    /// Returns the vendor's ID.
-    pub fn id(&self) -> u16 {
-        self.id
+impl<T: Copy + fmt::UpperHex, C: 'static> fmt::UpperHex for UsbIdWithChildren<T, C> {
+    /// Formats the type's ID as uppercase hex, zero-padded to the natural width of its
+    /// type, e.g. `000C`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = 2 * core::mem::size_of::<T>();
+        write!(f, "{:0width$X}", self.id, width = width)
     }
+}
 
-    /// Returns the vendor's name.
-    pub fn name(&self) -> &'static str {
+impl<T: Copy, C: 'static> AsRef<str> for UsbIdWithChildren<T, C> {
+    /// Returns the type's name, like `name()`.
+    fn as_ref(&self) -> &str {
         self.name
     }
+}
 
-    /// Returns an iterator over the vendor's [`Device`]s.
-    pub fn devices(&self) -> impl Iterator<Item = &'static Device> {
-        self.devices.iter()
+impl<T: Copy, C: 'static> AsRef<[C]> for UsbIdWithChildren<T, C> {
+    /// Returns the type's children, in database order.
+    fn as_ref(&self) -> &[C] {
+        self.children
     }
 }
 
-/// Represents a single device in the USB database.
-///
-/// Every device has a corresponding vendor, a device ID, a pretty name,
-/// and a list of associated [`Interface`]s.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct Device {
-    vendor_id: u16,
-    id: u16,
-    name: &'static str,
-    interfaces: &'static [Interface],
+/// Serializes a numeric ID as a `0x`-prefixed, zero-padded hex string (e.g. `"0x1d6b"`).
+#[cfg(feature = "serde_hex_ids")]
+fn serialize_hex_id<S, T>(id: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+    T: core::fmt::LowerHex,
+{
+    let width = 2 + 2 * core::mem::size_of::<T>();
+    serializer.collect_str(&format_args!("{:#0width$x}", id, width = width))
 }
 
-impl Device {
-    /// Returns the [`Device`] corresponding to the given vendor and product IDs,
-    /// or `None` if no such device exists in the DB.
-    ///
-    /// ```
-    /// use usb_ids::Device;
-    /// let device = Device::from_vid_pid(0x1d6b, 0x0003).unwrap();
-    /// assert_eq!(device.name(), "3.0 root hub");
-    /// ```
-    pub fn from_vid_pid(vid: u16, pid: u16) -> Option<&'static Device> {
-        let vendor = Vendor::from_id(vid);
+/// An iterator over one of the USB database's top-level tables.
+///
+/// Entries are yielded in database order, i.e. the order they appear in `usb.ids`.
+///
+/// Not designed to be used directly; use one of the type aliases instead.
+#[derive(Clone, Debug)]
+pub struct MapIter<K: 'static, V: 'static>(phf::ordered_map::Values<'static, K, V>);
+
+impl<K, V> Iterator for MapIter<K, V> {
+    type Item = &'static V;
 
-        vendor.and_then(|v| v.devices().find(|d| d.id == pid))
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
     }
 
-    /// Returns the [`Vendor`] that this device belongs to.
-    ///
-    /// Looking up a vendor by device is cheap (`O(1)`).
-    pub fn vendor(&self) -> &'static Vendor {
-        USB_IDS.get(&self.vendor_id).unwrap()
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
     }
+}
 
-    /// Returns a tuple of (vendor id, device/"product" id) for this device.
+impl<K, V> DoubleEndedIterator for MapIter<K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<K, V> ExactSizeIterator for MapIter<K, V> {}
+
+impl<K, V> FusedIterator for MapIter<K, V> {}
+
+/// Maps a parent entity to its children, e.g. a vendor to its devices.
+type ChildrenFn<P, C> = fn(<P as Iterator>::Item) -> &'static [C];
+
+/// An iterator over the children of one or more entities in the USB database,
+/// e.g. the devices of a single vendor or the devices of every vendor.
+///
+/// Not designed to be used directly; use one of the type aliases instead.
+#[derive(Clone, Debug)]
+pub struct FlatIter<P: Iterator, C: 'static> {
+    parents: Option<(P, ChildrenFn<P, C>)>,
+    front: core::slice::Iter<'static, C>,
+    back: core::slice::Iter<'static, C>,
+    len: usize,
+}
+
+impl<P: Iterator, C> FlatIter<P, C> {
+    /// Creates an iterator over the children of every entity yielded by `parents`, of which
+    /// there are `len` in total.
     ///
-    /// This is convenient for interactions with other USB libraries.
-    pub fn as_vid_pid(&self) -> (u16, u16) {
-        (self.vendor_id, self.id)
+    /// The build script counts each kind of child, so `len` is one of its `*_COUNT`
+    /// constants; counting here would mean walking every parent up front.
+    fn new(parents: P, children: ChildrenFn<P, C>, len: usize) -> Self {
+        FlatIter {
+            parents: Some((parents, children)),
+            front: [].iter(),
+            back: [].iter(),
+            len,
+        }
     }
 
-    /// Returns the device's ID.
-    pub fn id(&self) -> u16 {
-        self.id
+    /// Creates an iterator over the children of a single entity.
+    fn from_slice(children: &'static [C]) -> Self {
+        FlatIter {
+            parents: None,
+            front: children.iter(),
+            back: [].iter(),
+            len: children.len(),
+        }
     }
+}
 
-    /// Returns the device's name.
-    pub fn name(&self) -> &'static str {
-        self.name
+impl<P: Iterator, C> Iterator for FlatIter<P, C> {
+    type Item = &'static C;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(child) = self.front.next() {
+                self.len -= 1;
+                return Some(child);
+            }
+
+            match self
+                .parents
+                .as_mut()
+                .and_then(|(parents, children)| parents.next().map(*children))
+            {
+                Some(next) => self.front = next.iter(),
+                None => {
+                    // No parents left, so anything remaining was already claimed from the back.
+                    let child = self.back.next()?;
+                    self.len -= 1;
+                    return Some(child);
+                }
+            }
+        }
     }
 
-    /// Returns an iterator over the device's [`Interface`]s.
-    ///
-    /// **NOTE**: The USB database does not include interface information for
-    /// most devices. This list is not authoritative.
-    pub fn interfaces(&self) -> impl Iterator<Item = &'static Interface> {
-        self.interfaces.iter()
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
     }
 }
 
-/// Represents an interface to a USB device in the USB database.
+impl<P: DoubleEndedIterator, C> DoubleEndedIterator for FlatIter<P, C> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(child) = self.back.next_back() {
+                self.len -= 1;
+                return Some(child);
+            }
+
+            match self
+                .parents
+                .as_mut()
+                .and_then(|(parents, children)| parents.next_back().map(*children))
+            {
+                Some(prev) => self.back = prev.iter(),
+                None => {
+                    let child = self.front.next_back()?;
+                    self.len -= 1;
+                    return Some(child);
+                }
+            }
+        }
+    }
+}
+
+impl<P: Iterator, C> ExactSizeIterator for FlatIter<P, C> {}
+
+impl<P: Iterator, C> FusedIterator for FlatIter<P, C> {}
+
+/// An iterator over [`Vendor`]s.
+pub type VendorIter = MapIter<u16, Vendor>;
+
+/// An iterator over [`Device`]s.
+pub type DeviceIter = FlatIter<VendorIter, Device>;
+
+/// An iterator over [`Interface`]s.
+pub type InterfaceIter = FlatIter<DeviceIter, Interface>;
+
+/// An iterator over [`Class`]es.
+#[cfg(not(feature = "vendors-only"))]
+pub type ClassIter = MapIter<u8, Class>;
+
+/// An iterator over [`SubClass`]es.
+#[cfg(not(feature = "vendors-only"))]
+pub type SubClassIter = FlatIter<ClassIter, SubClass>;
+
+/// An iterator over [`Protocol`]s.
+#[cfg(not(feature = "vendors-only"))]
+pub type ProtocolIter = FlatIter<SubClassIter, Protocol>;
+
+/// An iterator over [`Language`]s.
+#[cfg(not(feature = "no-languages"))]
+pub type LanguageIter = MapIter<u16, Language>;
+
+/// An iterator over [`Dialect`]s.
+#[cfg(not(feature = "no-languages"))]
+pub type DialectIter = FlatIter<LanguageIter, Dialect>;
+
+/// An iterator over [`HidUsagePage`]s.
+#[cfg(not(feature = "no-hid"))]
+pub type HidUsagePageIter = MapIter<u8, HidUsagePage>;
+
+/// An iterator over [`HidUsage`]s.
+#[cfg(not(feature = "no-hid"))]
+pub type HidUsageIter = FlatIter<HidUsagePageIter, HidUsage>;
+
+/// An iterator over [`AudioTerminal`]s.
+#[cfg(not(feature = "no-audio"))]
+pub type AudioTerminalIter = MapIter<u16, AudioTerminal>;
+
+/// An iterator over [`Hid`]s.
+#[cfg(not(feature = "no-hid"))]
+pub type HidIter = MapIter<u8, Hid>;
+
+/// An iterator over [`HidItemType`]s.
+#[cfg(not(feature = "no-hid"))]
+pub type HidItemTypeIter = MapIter<u8, HidItemType>;
+
+/// An iterator over [`Bias`]es.
+#[cfg(not(feature = "no-hid"))]
+pub type BiasIter = MapIter<u8, Bias>;
+
+/// An iterator over [`Phy`]s.
+#[cfg(not(feature = "no-hid"))]
+pub type PhyIter = MapIter<u8, Phy>;
+
+/// An iterator over [`HidCountryCode`]s.
+#[cfg(not(feature = "no-hid"))]
+pub type HidCountryCodeIter = MapIter<u8, HidCountryCode>;
+
+/// An iterator over [`VideoTerminal`]s.
+#[cfg(not(feature = "no-video"))]
+pub type VideoTerminalIter = MapIter<u16, VideoTerminal>;
+
+/// Returns the number of vendors in the USB database.
 ///
-/// Every interface has an interface ID (which is an index on the device)
-/// and a pretty name.
+/// ```
+/// assert_eq!(usb_ids::total_vendor_count(), usb_ids::Vendors::iter().count());
+/// ```
+pub fn total_vendor_count() -> usize {
+    VENDOR_COUNT
+}
+
+/// Returns the number of devices, across all vendors, in the USB database.
 ///
-/// **NOTE**: The USB database is not a canonical or authoritative source
-/// of interface information for devices. Users who wish to discover interfaces
-/// on their USB devices should query those devices directly.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct Interface {
-    id: u8,
-    name: &'static str,
+/// Unlike counting via [`Devices::iter`], this is cheap (`O(1)`).
+pub fn total_device_count() -> usize {
+    DEVICE_COUNT
 }
 
-impl Interface {
-    /// Returns the interface's ID.
-    pub fn id(&self) -> u8 {
-        self.id
-    }
+/// Returns the [`Vendor`] and [`Device`] corresponding to the given vendor and product IDs,
+/// or `None` if no such device exists in the DB.
+///
+/// Both lookups are cheap (`O(1)`).
+///
+/// ```
+/// let (vendor, device) = usb_ids::lookup_vendor_and_device(0x1d6b, 0x0003).unwrap();
+/// assert_eq!(vendor.name(), "Linux Foundation");
+/// assert_eq!(device.name(), "3.0 root hub");
+/// ```
+pub fn lookup_vendor_and_device(vid: u16, pid: u16) -> Option<(&'static Vendor, &'static Device)> {
+    let device = Device::from_vid_pid(vid, pid)?;
 
-    /// Returns the interface's name.
-    pub fn name(&self) -> &'static str {
-        self.name
-    }
+    Some((device.vendor(), device))
 }
 
-/// Represents a USB device class in the USB database.
+/// Returns the [`Class`], [`SubClass`], and [`Protocol`] corresponding to the given class,
+/// subclass, and protocol IDs (e.g. a device's `bDeviceClass`, `bDeviceSubClass`, and
+/// `bDeviceProtocol`), or `None` if no such protocol exists in the DB.
 ///
-/// Every device class has a class ID, a pretty name, and a
-/// list of associated [`SubClass`]s.
+/// All three lookups are cheap (`O(1)`).
 ///
 /// ```
-/// use usb_ids::{Class, Classes, FromId};
-/// let class = Class::from_id(0x03).unwrap();
+/// let (class, subclass, protocol) = usb_ids::lookup_class_triple(0x03, 0x01, 0x01).unwrap();
 /// assert_eq!(class.name(), "Human Interface Device");
+/// assert_eq!(subclass.name(), "Boot Interface Subclass");
+/// assert_eq!(protocol.name(), "Keyboard");
 /// ```
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct Class {
-    id: u8,
-    name: &'static str,
-    sub_classes: &'static [SubClass],
-}
+#[cfg(not(feature = "vendors-only"))]
+pub fn lookup_class_triple(
+    class_id: u8,
+    subclass_id: u8,
+    protocol_id: u8,
+) -> Option<(&'static Class, &'static SubClass, &'static Protocol)> {
+    let protocol = Protocol::from_cid_scid_pid(class_id, subclass_id, protocol_id)?;
+    let subclass = protocol.subclass();
 
-impl Class {
-    /// Returns the class's ID.
-    pub fn id(&self) -> u8 {
-        self.id
-    }
+    Some((subclass.class(), subclass, protocol))
+}
 
-    /// Returns the class's name.
-    pub fn name(&self) -> &'static str {
-        self.name
+/// Returns a human-readable description of the given vendor and product IDs, or `None` if
+/// the vendor isn't in the USB database.
+///
+/// The description is the [`Device`]'s `Display` form if the device is known, and the
+/// [`Vendor`]'s otherwise.
+///
+/// ```
+/// use usb_ids::describe_vid_pid;
+///
+/// assert_eq!(
+///     describe_vid_pid(0x1d6b, 0x0003).unwrap(),
+///     "Linux Foundation 3.0 root hub (0x1d6b:0x0003)"
+/// );
+/// assert_eq!(describe_vid_pid(0x1d6b, 0x9999).unwrap(), "Linux Foundation (0x1d6b)");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn describe_vid_pid(vid: u16, pid: u16) -> Option<alloc::string::String> {
+    match Device::from_vid_pid(vid, pid) {
+        Some(device) => Some(alloc::format!("{}", device)),
+        None => Vendor::from_id(vid).map(|vendor| alloc::format!("{}", vendor)),
     }
+}
 
-    /// Returns an iterator over the class's [`SubClass`]s.
-    pub fn sub_classes(&self) -> impl Iterator<Item = &'static SubClass> {
-        self.sub_classes.iter()
+/// Returns a human-readable description of the given class, subclass, and protocol IDs, or
+/// `None` if the class isn't in the USB database.
+///
+/// The description is the `Display` form of the most specific of the [`Protocol`],
+/// [`SubClass`], and [`Class`] that is known.
+///
+/// ```
+/// use usb_ids::describe_class_triple;
+///
+/// assert_eq!(
+///     describe_class_triple(0x03, 0x01, 0x01).unwrap(),
+///     "Human Interface Device / Boot Interface Subclass / Keyboard (0x03/0x01/0x01)"
+/// );
+/// assert_eq!(
+///     describe_class_triple(0x03, 0x01, 0xaa).unwrap(),
+///     "Human Interface Device / Boot Interface Subclass (0x03/0x01)"
+/// );
+/// assert_eq!(
+///     describe_class_triple(0x03, 0xaa, 0xaa).unwrap(),
+///     "Human Interface Device (0x03)"
+/// );
+/// ```
+#[cfg(all(feature = "alloc", not(feature = "vendors-only")))]
+pub fn describe_class_triple(
+    class_id: u8,
+    subclass_id: u8,
+    protocol_id: u8,
+) -> Option<alloc::string::String> {
+    if let Some(protocol) = Protocol::from_cid_scid_pid(class_id, subclass_id, protocol_id) {
+        Some(alloc::format!("{}", protocol))
+    } else if let Some(subclass) = SubClass::from_cid_scid(class_id, subclass_id) {
+        Some(alloc::format!("{}", subclass))
+    } else {
+        Class::from_id(class_id).map(|class| alloc::format!("{}", class))
     }
 }
 
-/// Represents a class subclass in the USB database. Subclasses are part of the
-/// USB class code triplet (base class, subclass, protocol).
+/// The results of [`search_all`]: every entry in the USB database whose name matches a
+/// query, grouped by type.
 ///
-/// Contained within a [`Class`] and may contain a list of associated
-/// [`Protocol`]s.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct SubClass {
-    class_id: u8,
-    id: u8,
-    name: &'static str,
-    protocols: &'static [Protocol],
+/// Every table is searched, including the children of entries (devices, interfaces,
+/// subclasses, protocols, HID usages, and dialects), except those disabled by the crate's
+/// features.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SearchResults {
+    vendors: alloc::vec::Vec<&'static Vendor>,
+    devices: alloc::vec::Vec<&'static Device>,
+    interfaces: alloc::vec::Vec<&'static Interface>,
+    #[cfg(not(feature = "vendors-only"))]
+    classes: alloc::vec::Vec<&'static Class>,
+    #[cfg(not(feature = "vendors-only"))]
+    subclasses: alloc::vec::Vec<&'static SubClass>,
+    #[cfg(not(feature = "vendors-only"))]
+    protocols: alloc::vec::Vec<&'static Protocol>,
+    #[cfg(not(feature = "no-audio"))]
+    audio_terminals: alloc::vec::Vec<&'static AudioTerminal>,
+    #[cfg(not(feature = "no-hid"))]
+    hids: alloc::vec::Vec<&'static Hid>,
+    #[cfg(not(feature = "no-hid"))]
+    hid_item_types: alloc::vec::Vec<&'static HidItemType>,
+    #[cfg(not(feature = "no-hid"))]
+    biases: alloc::vec::Vec<&'static Bias>,
+    #[cfg(not(feature = "no-hid"))]
+    phys: alloc::vec::Vec<&'static Phy>,
+    #[cfg(not(feature = "no-hid"))]
+    hid_usage_pages: alloc::vec::Vec<&'static HidUsagePage>,
+    #[cfg(not(feature = "no-hid"))]
+    hid_usages: alloc::vec::Vec<&'static HidUsage>,
+    #[cfg(not(feature = "no-languages"))]
+    languages: alloc::vec::Vec<&'static Language>,
+    #[cfg(not(feature = "no-languages"))]
+    dialects: alloc::vec::Vec<&'static Dialect>,
+    #[cfg(not(feature = "no-hid"))]
+    hid_country_codes: alloc::vec::Vec<&'static HidCountryCode>,
+    #[cfg(not(feature = "no-video"))]
+    video_terminals: alloc::vec::Vec<&'static VideoTerminal>,
 }
 
-impl SubClass {
-    /// Returns the [`SubClass`] corresponding to the given class and subclass IDs,
-    /// or `None` if no such subclass exists in the DB.
-    ///
-    /// ```
-    /// use usb_ids::SubClass;
-    /// let subclass = SubClass::from_cid_scid(0x02, 0x03).unwrap();
-    /// assert_eq!(subclass.name(), "Telephone");
-    ///
-    /// assert!(SubClass::from_cid_scid(0x3c, 0x02).is_none());
-    /// ```
-    pub fn from_cid_scid(class_id: u8, id: u8) -> Option<&'static Self> {
-        let class = Class::from_id(class_id);
+#[cfg(feature = "alloc")]
+impl SearchResults {
+    /// Returns `true` if nothing matched the query.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
 
-        class.and_then(|c| c.sub_classes().find(|s| s.id == id))
+    /// Returns an iterator over the matching vendors.
+    pub fn vendors(&self) -> impl ExactSizeIterator<Item = &'static Vendor> + '_ {
+        self.vendors.iter().copied()
     }
 
-    /// Returns the [`Class`] that this subclass belongs to.
-    ///
-    /// Looking up a class by subclass is cheap (`O(1)`).
-    ///
-    /// ```
-    /// use usb_ids::SubClass;
-    /// let subclass = SubClass::from_cid_scid(0x02, 0x03).unwrap();
-    /// let class = subclass.class();
-    /// assert_eq!(class.id(), 0x02);
-    /// ```
-    pub fn class(&self) -> &'static Class {
-        USB_CLASSES.get(&self.class_id).unwrap()
+    /// Returns an iterator over the matching devices.
+    pub fn devices(&self) -> impl ExactSizeIterator<Item = &'static Device> + '_ {
+        self.devices.iter().copied()
     }
 
-    /// Returns a tuple of (class id, subclass id) for this subclass.
-    ///
-    /// This is convenient for interactions with other USB libraries.
-    pub fn as_cid_scid(&self) -> (u8, u8) {
-        (self.class_id, self.id)
+    /// Returns an iterator over the matching interfaces.
+    pub fn interfaces(&self) -> impl ExactSizeIterator<Item = &'static Interface> + '_ {
+        self.interfaces.iter().copied()
     }
 
-    /// Returns the subclass' ID.
-    pub fn id(&self) -> u8 {
-        self.id
+    /// Returns an iterator over the matching classes.
+    #[cfg(not(feature = "vendors-only"))]
+    pub fn classes(&self) -> impl ExactSizeIterator<Item = &'static Class> + '_ {
+        self.classes.iter().copied()
     }
 
-    /// Returns the subclass' name.
-    pub fn name(&self) -> &'static str {
-        self.name
+    /// Returns an iterator over the matching subclasses.
+    #[cfg(not(feature = "vendors-only"))]
+    pub fn subclasses(&self) -> impl ExactSizeIterator<Item = &'static SubClass> + '_ {
+        self.subclasses.iter().copied()
     }
 
-    /// Returns an iterator over the subclasses's [`Protocol`]s.
-    ///
-    /// **NOTE**: The USB database nor USB-IF includes protocol information for
-    /// all subclassess. This list is not authoritative.
-    pub fn protocols(&self) -> impl Iterator<Item = &'static Protocol> {
-        self.protocols.iter()
+    /// Returns an iterator over the matching protocols.
+    #[cfg(not(feature = "vendors-only"))]
+    pub fn protocols(&self) -> impl ExactSizeIterator<Item = &'static Protocol> + '_ {
+        self.protocols.iter().copied()
     }
-}
 
-/// These are tags for UsbId type aliases to make them unique and allow a
-/// [`FromId`] for each alias. The values are arbitrary but must be unique.
-///
-/// [`std::marker::PhantomData`] would be nicer but was unable to figure out a
-/// generic way to add the _tag: PhantomData in the ToToken trait
-/// implementation within build.rs
-const PROTOCOL_TAG: u8 = 0;
-const AT_TAG: u8 = 1;
-const HID_TAG: u8 = 2;
-const HID_TYPE_TAG: u8 = 3;
-const HID_USAGE_TAG: u8 = 4;
-const BIAS_TAG: u8 = 5;
-const PHY_TAG: u8 = 6;
-const DIALECT_TAG: u8 = 7;
-const HCC_TAG: u8 = 8;
-const VT_TAG: u8 = 9;
+    /// Returns an iterator over the matching audio terminal types.
+    #[cfg(not(feature = "no-audio"))]
+    pub fn audio_terminals(&self) -> impl ExactSizeIterator<Item = &'static AudioTerminal> + '_ {
+        self.audio_terminals.iter().copied()
+    }
 
-/// Represents a subclass protocol in the USB database.
-///
-/// Protocols are part of the USB class code triplet (base class, subclass,
-/// protocol), contained within a [`SubClass`].
-pub type Protocol = UsbId<PROTOCOL_TAG, u8>;
+    /// Returns an iterator over the matching HID descriptor types.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn hids(&self) -> impl ExactSizeIterator<Item = &'static Hid> + '_ {
+        self.hids.iter().copied()
+    }
 
-impl Protocol {
-    /// Returns the [`Protocol`] corresponding to the given class, subclass, and protocol IDs,
-    /// or `None` if no such protocol exists in the DB.
-    ///
-    /// ```
-    /// use usb_ids::Protocol;
-    /// let protocol = Protocol::from_cid_scid_pid(0x02, 0x02, 0x05).unwrap();
-    /// assert_eq!(protocol.name(), "AT-commands (3G)");
-    /// ```
-    pub fn from_cid_scid_pid(class_id: u8, subclass_id: u8, id: u8) -> Option<&'static Self> {
-        let subclass = SubClass::from_cid_scid(class_id, subclass_id);
+    /// Returns an iterator over the matching HID item types.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn hid_item_types(&self) -> impl ExactSizeIterator<Item = &'static HidItemType> + '_ {
+        self.hid_item_types.iter().copied()
+    }
+
+    /// Returns an iterator over the matching biases.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn biases(&self) -> impl ExactSizeIterator<Item = &'static Bias> + '_ {
+        self.biases.iter().copied()
+    }
+
+    /// Returns an iterator over the matching physical descriptor items.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn phys(&self) -> impl ExactSizeIterator<Item = &'static Phy> + '_ {
+        self.phys.iter().copied()
+    }
+
+    /// Returns an iterator over the matching HID usage pages.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn hid_usage_pages(&self) -> impl ExactSizeIterator<Item = &'static HidUsagePage> + '_ {
+        self.hid_usage_pages.iter().copied()
+    }
 
-        subclass.and_then(|s| s.protocols().find(|p| p.id == id))
+    /// Returns an iterator over the matching HID usages.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn hid_usages(&self) -> impl ExactSizeIterator<Item = &'static HidUsage> + '_ {
+        self.hid_usages.iter().copied()
+    }
+
+    /// Returns an iterator over the matching languages.
+    #[cfg(not(feature = "no-languages"))]
+    pub fn languages(&self) -> impl ExactSizeIterator<Item = &'static Language> + '_ {
+        self.languages.iter().copied()
+    }
+
+    /// Returns an iterator over the matching dialects.
+    #[cfg(not(feature = "no-languages"))]
+    pub fn dialects(&self) -> impl ExactSizeIterator<Item = &'static Dialect> + '_ {
+        self.dialects.iter().copied()
+    }
+
+    /// Returns an iterator over the matching HID country codes.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn hid_country_codes(&self) -> impl ExactSizeIterator<Item = &'static HidCountryCode> + '_ {
+        self.hid_country_codes.iter().copied()
+    }
+
+    /// Returns an iterator over the matching video terminal types.
+    #[cfg(not(feature = "no-video"))]
+    pub fn video_terminals(&self) -> impl ExactSizeIterator<Item = &'static VideoTerminal> + '_ {
+        self.video_terminals.iter().copied()
     }
 }
 
-/// Represents an audio terminal type in the USB database.
+/// Returns `true` if `haystack` contains `needle`, ignoring ASCII case.
+#[cfg(feature = "alloc")]
+fn contains_ignore_ascii_case(haystack: &str, needle: &str) -> bool {
+    needle.is_empty()
+        || haystack
+            .as_bytes()
+            .windows(needle.len())
+            .any(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+/// Searches every table in the USB database for entries whose names contain `query`,
+/// ignoring ASCII case.
+///
+/// This is very expensive (`O(n)` in the size of the entire database), and is intended for
+/// interactive "type to search" uses; prefer the per-type `search_by_name` methods when only
+/// one kind of entry is wanted.
 ///
 /// ```
-/// use usb_ids::{AudioTerminal, FromId};
-/// let audio_terminal = AudioTerminal::from_id(0x0201).unwrap();
-/// assert_eq!(audio_terminal.name(), "Microphone");
+/// use usb_ids::search_all;
+///
+/// let results = search_all("linux foundation");
+/// assert!(results.vendors().any(|v| v.id() == 0x1d6b));
+///
+/// assert!(search_all("no such thing, surely").is_empty());
 /// ```
-pub type AudioTerminal = UsbId<AT_TAG, u16>;
+#[cfg(feature = "alloc")]
+pub fn search_all(query: &str) -> SearchResults {
+    fn matching<T: AsRef<str> + 'static>(
+        iter: impl Iterator<Item = &'static T>,
+        query: &str,
+    ) -> alloc::vec::Vec<&'static T> {
+        iter.filter(|x| contains_ignore_ascii_case(x.as_ref(), query))
+            .collect()
+    }
 
-/// Represents a HID descriptor type in the USB database.
+    SearchResults {
+        vendors: matching(Vendors::iter(), query),
+        devices: matching(Devices::iter(), query),
+        interfaces: matching(all_interfaces(), query),
+        #[cfg(not(feature = "vendors-only"))]
+        classes: matching(Classes::iter(), query),
+        #[cfg(not(feature = "vendors-only"))]
+        subclasses: matching(SubClasses::iter(), query),
+        #[cfg(not(feature = "vendors-only"))]
+        protocols: matching(Protocols::iter(), query),
+        #[cfg(not(feature = "no-audio"))]
+        audio_terminals: matching(AudioTerminals::iter(), query),
+        #[cfg(not(feature = "no-hid"))]
+        hids: matching(Hids::iter(), query),
+        #[cfg(not(feature = "no-hid"))]
+        hid_item_types: matching(HidItemTypes::iter(), query),
+        #[cfg(not(feature = "no-hid"))]
+        biases: matching(Biases::iter(), query),
+        #[cfg(not(feature = "no-hid"))]
+        phys: matching(Phys::iter(), query),
+        #[cfg(not(feature = "no-hid"))]
+        hid_usage_pages: matching(HidUsagePages::iter(), query),
+        #[cfg(not(feature = "no-hid"))]
+        hid_usages: matching(all_hid_usages(), query),
+        #[cfg(not(feature = "no-languages"))]
+        languages: matching(Languages::iter(), query),
+        #[cfg(not(feature = "no-languages"))]
+        dialects: matching(Dialects::iter(), query),
+        #[cfg(not(feature = "no-hid"))]
+        hid_country_codes: matching(HidCountryCodes::iter(), query),
+        #[cfg(not(feature = "no-video"))]
+        video_terminals: matching(VideoTerminals::iter(), query),
+    }
+}
+
+/// Returns the number of classes in the USB database.
+#[cfg(not(feature = "vendors-only"))]
+pub fn total_class_count() -> usize {
+    CLASS_COUNT
+}
+
+/// Returns an iterator over the names of all vendors in the USB database.
 ///
 /// ```
-/// use usb_ids::{Hid, FromId};
-/// let hid = Hid::from_id(0x22).unwrap();
-/// assert_eq!(hid.name(), "Report");
+/// assert!(usb_ids::all_vendor_names().any(|name| name == "Linux Foundation"));
 /// ```
-pub type Hid = UsbId<HID_TAG, u8>;
+pub fn all_vendor_names() -> impl Iterator<Item = &'static str> {
+    Vendors::iter().map(|v| v.name())
+}
 
-/// Represents a HID descriptor item type in the USB database.
+/// Returns an iterator over the `(vendor name, device name)` pairs of all devices in the
+/// USB database.
 ///
 /// ```
-/// use usb_ids::{HidItemType, FromId};
-/// let hid_item_type = HidItemType::from_id(0xb4).unwrap();
-/// assert_eq!(hid_item_type.name(), "Pop");
+/// assert!(usb_ids::all_device_names().any(|names| names == ("Linux Foundation", "3.0 root hub")));
 /// ```
-pub type HidItemType = UsbId<HID_TYPE_TAG, u8>;
+pub fn all_device_names() -> impl Iterator<Item = (&'static str, &'static str)> {
+    Devices::iter().map(|d| (d.vendor().name(), d.name()))
+}
 
-/// Represents a HID usage page in the USB database.
+/// Returns an iterator over the names of all classes in the USB database.
+#[cfg(not(feature = "vendors-only"))]
+pub fn all_class_names() -> impl Iterator<Item = &'static str> {
+    Classes::iter().map(|c| c.name())
+}
+
+/// Returns an iterator over the names of all languages in the USB database.
+#[cfg(not(feature = "no-languages"))]
+pub fn all_language_names() -> impl Iterator<Item = &'static str> {
+    Languages::iter().map(|l| l.name())
+}
+
+/// Returns an iterator over the names of all HID usage pages in the USB database.
+#[cfg(not(feature = "no-hid"))]
+pub fn all_hid_usage_page_names() -> impl Iterator<Item = &'static str> {
+    HidUsagePages::iter().map(|p| p.name())
+}
+
+/// Returns an iterator over every interface in the USB database, across all vendors and
+/// devices.
 ///
-/// Every HID usage page has a usage page ID, a pretty name, and a list of
-/// associated [`HidUsage`]s.
+/// Most devices have no interfaces in the database, so this yields far fewer items than
+/// [`Devices::iter`].
+pub fn all_interfaces() -> InterfaceIter {
+    InterfaceIter::new(Devices::iter(), |d| d.interfaces, INTERFACE_COUNT)
+}
+
+/// Returns an iterator over every HID usage in the USB database, across all usage pages.
+#[cfg(not(feature = "no-hid"))]
+pub fn all_hid_usages() -> HidUsageIter {
+    HidUsageIter::new(HidUsagePages::iter(), |p| p.children, HID_USAGE_COUNT)
+}
+
+/// Returns an iterator over every device in the USB database, paired with its vendor.
 ///
 /// ```
-/// use usb_ids::{HidUsagePage, FromId};
-/// let hid_usage_page = HidUsagePage::from_id(0x01).unwrap();
-/// assert_eq!(hid_usage_page.name(), "Generic Desktop Controls");
-///
-/// for usage in hid_usage_page.usages() {
-///   println!("usage: {}", usage.name());
+/// for (vendor, device) in usb_ids::all_vendor_devices() {
+///     assert_eq!(device.vendor(), vendor);
 /// }
 /// ```
-pub type HidUsagePage = UsbIdWithChildren<u8, HidUsage>;
+pub fn all_vendor_devices() -> impl Iterator<Item = (&'static Vendor, &'static Device)> {
+    Vendors::iter().flat_map(|v| v.devices().map(move |d| (v, d)))
+}
 
-impl HidUsagePage {
-    /// Returns an iterator over the page's [`HidUsage`]s.
-    pub fn usages(&self) -> impl Iterator<Item = &'static HidUsage> {
-        self.children()
+/// Returns an iterator over every protocol in the USB database, along with its class and
+/// subclass.
+#[cfg(not(feature = "vendors-only"))]
+pub fn all_class_triples(
+) -> impl Iterator<Item = (&'static Class, &'static SubClass, &'static Protocol)> {
+    Classes::iter().flat_map(|c| {
+        c.sub_classes()
+            .flat_map(move |s| s.protocols().map(move |p| (c, s, p)))
+    })
+}
+
+/// Returns an iterator over every HID usage in the USB database, paired with its page.
+#[cfg(not(feature = "no-hid"))]
+pub fn all_hid_page_usages() -> impl Iterator<Item = (&'static HidUsagePage, &'static HidUsage)> {
+    HidUsagePages::iter().flat_map(|p| p.usages().map(move |u| (p, u)))
+}
+
+/// Returns an iterator over every dialect in the USB database, paired with its language.
+#[cfg(not(feature = "no-languages"))]
+pub fn all_language_dialects() -> impl Iterator<Item = (&'static Language, &'static Dialect)> {
+    Languages::iter().flat_map(|l| l.dialects().map(move |d| (l, d)))
+}
+
+/// An index from names to entries, backing the `from_name` lookups.
+///
+/// With the `name-index` feature, the index is built the first time it's used (so callers
+/// who never look up by name don't pay for it), making every later lookup `O(1)`.
+/// Otherwise, it's empty and each lookup scans the entries in full.
+struct NameIndex<T: 'static> {
+    #[cfg(feature = "name-index")]
+    map: std::sync::OnceLock<std::collections::HashMap<&'static str, &'static T>>,
+    #[cfg(not(feature = "name-index"))]
+    _marker: core::marker::PhantomData<&'static T>,
+}
+
+impl<T: AsRef<str>> NameIndex<T> {
+    const fn new() -> Self {
+        NameIndex {
+            #[cfg(feature = "name-index")]
+            map: std::sync::OnceLock::new(),
+            #[cfg(not(feature = "name-index"))]
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns the first of `entries` with exactly the given name.
+    #[cfg(feature = "name-index")]
+    fn find(&self, entries: impl Iterator<Item = &'static T>, name: &str) -> Option<&'static T> {
+        let map = self.map.get_or_init(|| {
+            let mut map = std::collections::HashMap::new();
+            for entry in entries {
+                // Keep the first of any duplicate names, like a scan would.
+                map.entry(entry.as_ref()).or_insert(entry);
+            }
+            map
+        });
+
+        map.get(name).copied()
+    }
+
+    /// Returns the first of `entries` with exactly the given name.
+    #[cfg(not(feature = "name-index"))]
+    fn find(
+        &self,
+        mut entries: impl Iterator<Item = &'static T>,
+        name: &str,
+    ) -> Option<&'static T> {
+        entries.find(|entry| entry.as_ref() == name)
     }
 }
 
-/// Represents a HID usage type in the USB database.
+/// Collects `iter` into a `Vec` sorted by `key`, for the `iter_sorted_by_*` methods.
+#[cfg(feature = "alloc")]
+fn sorted_by_key<T, K, F>(
+    iter: impl Iterator<Item = &'static T>,
+    key: F,
+) -> alloc::vec::IntoIter<&'static T>
+where
+    K: Ord,
+    F: FnMut(&&'static T) -> K,
+{
+    let mut items: alloc::vec::Vec<_> = iter.collect();
+    items.sort_unstable_by_key(key);
+    items.into_iter()
+}
+
+/// An abstraction for iterating over all vendors in the USB database.
+pub struct Vendors;
+impl Vendors {
+    /// Returns an iterator over all vendors in the USB database.
+    pub fn iter() -> VendorIter {
+        MapIter(USB_IDS.values())
+    }
+
+    /// Returns an iterator over all vendors in the USB database, keyed by ID, e.g. for
+    /// collecting into a map that can be queried by ID.
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use usb_ids::Vendors;
+    ///
+    /// let vendors: BTreeMap<_, _> = Vendors::iter_with_ids().collect();
+    /// assert_eq!(vendors[&0x1d6b].name(), "Linux Foundation");
+    /// ```
+    pub fn iter_with_ids() -> impl ExactSizeIterator<Item = (u16, &'static Vendor)> {
+        Self::iter().map(|v| (v.id, v))
+    }
+
+    /// Returns an iterator over all vendors in the USB database that have at least one
+    /// device.
+    pub fn iter_with_devices() -> impl Iterator<Item = &'static Vendor> {
+        Self::iter().filter(|v| !v.devices.is_empty())
+    }
+
+    /// Returns an iterator over all vendors in the USB database whose names start with
+    /// `prefix`, e.g. for autocompletion.
+    ///
+    /// The comparison is case-sensitive; see [`Vendors::iter_with_prefix_ci`].
+    ///
+    /// ```
+    /// use usb_ids::Vendors;
+    /// assert!(Vendors::iter_with_prefix("Apple").any(|v| v.id() == 0x05ac));
+    /// ```
+    pub fn iter_with_prefix(prefix: &str) -> impl Iterator<Item = &'static Vendor> + '_ {
+        Self::iter().filter(move |v| v.name.starts_with(prefix))
+    }
+
+    /// Like [`Vendors::iter_with_prefix`], but compares case-insensitively (ASCII only).
+    pub fn iter_with_prefix_ci(prefix: &str) -> impl Iterator<Item = &'static Vendor> + '_ {
+        Self::iter().filter(move |v| {
+            matches!(
+                v.name.as_bytes().get(..prefix.len()),
+                Some(start) if start.eq_ignore_ascii_case(prefix.as_bytes())
+            )
+        })
+    }
+
+    /// Returns an iterator over all vendors in the USB database, sorted by name and then
+    /// by ID.
+    #[cfg(feature = "alloc")]
+    pub fn iter_sorted_by_name() -> alloc::vec::IntoIter<&'static Vendor> {
+        sorted_by_key(Self::iter(), |v| (v.name(), v.id()))
+    }
+
+    /// Returns an iterator over all vendors in the USB database, sorted by ID.
+    #[cfg(feature = "alloc")]
+    pub fn iter_sorted_by_id() -> alloc::vec::IntoIter<&'static Vendor> {
+        sorted_by_key(Self::iter(), |v| v.id())
+    }
+}
+
+/// An abstraction for iterating over all devices in the USB database.
 ///
 /// ```
-/// use usb_ids::{HidUsage, HidUsagePage, FromId};
+/// use usb_ids::Devices;
 ///
-/// let hid_usage_page = HidUsagePage::from_id(0x01).unwrap();
-/// for usage in hid_usage_page.usages() {
-///    println!("usage: {}", usage.name());
+/// for device in Devices::iter() {
+///     println!("vendor: {}, device: {}", device.vendor().name(), device.name());
 /// }
 /// ```
-pub type HidUsage = UsbId<HID_USAGE_TAG, u16>;
+pub struct Devices;
+impl Devices {
+    /// Returns an iterator over all devices in the USB database.
+    pub fn iter() -> DeviceIter {
+        DeviceIter::new(Vendors::iter(), |v| v.devices, DEVICE_COUNT)
+    }
 
-impl HidUsage {
-    /// Returns the [`HidUsage`] corresponding to the given usage page and usage ID,
-    /// or `None` if no such usage exists in the DB.
+    /// Returns an iterator over all devices in the USB database, keyed by their
+    /// `(vendor ID, product ID)` pairs, e.g. for collecting into a map that can be queried
+    /// by ID.
     ///
     /// ```
-    /// use usb_ids::HidUsage;
-    /// let hid_usage = HidUsage::from_pageid_uid(0x01, 0x002).unwrap();
-    /// assert_eq!(hid_usage.name(), "Mouse");
+    /// use std::collections::HashMap;
+    /// use usb_ids::Devices;
+    ///
+    /// let devices: HashMap<_, _> = Devices::iter_with_ids().collect();
+    /// assert_eq!(devices[&(0x1d6b, 0x0003)].name(), "3.0 root hub");
     /// ```
-    pub fn from_pageid_uid(page_id: u8, id: u16) -> Option<&'static Self> {
-        let page = HidUsagePage::from_id(page_id)?;
+    pub fn iter_with_ids() -> impl ExactSizeIterator<Item = ((u16, u16), &'static Device)> {
+        Self::iter().map(|d| (d.as_vid_pid(), d))
+    }
+}
 
-        page.children().find(|u| u.id() == id)
+/// An abstraction for iterating over all classes in the USB database.
+#[cfg(not(feature = "vendors-only"))]
+pub struct Classes;
+#[cfg(not(feature = "vendors-only"))]
+impl Classes {
+    /// Returns an iterator over all classes in the USB database.
+    pub fn iter() -> ClassIter {
+        MapIter(USB_CLASSES.values())
+    }
+
+    /// Returns an iterator over all classes in the USB database that have at least one
+    /// subclass.
+    pub fn iter_with_subclasses() -> impl Iterator<Item = &'static Class> {
+        Self::iter().filter(|c| !c.sub_classes.is_empty())
+    }
+
+    /// Returns an iterator over all classes in the USB database, sorted by name and then
+    /// by ID.
+    #[cfg(feature = "alloc")]
+    pub fn iter_sorted_by_name() -> alloc::vec::IntoIter<&'static Class> {
+        sorted_by_key(Self::iter(), |c| (c.name(), c.id()))
+    }
+
+    /// Returns an iterator over all classes in the USB database, sorted by ID.
+    #[cfg(feature = "alloc")]
+    pub fn iter_sorted_by_id() -> alloc::vec::IntoIter<&'static Class> {
+        sorted_by_key(Self::iter(), |c| c.id())
     }
 }
 
-/// Represents physical descriptor bias type in the USB database.
+/// An abstraction for iterating over all subclasses in the USB database.
 ///
 /// ```
-/// use usb_ids::{Bias, FromId};
-/// let bias = Bias::from_id(0x02).unwrap();
-/// assert_eq!(bias.name(), "Left Hand");
+/// use usb_ids::SubClasses;
+///
+/// for subclass in SubClasses::iter() {
+///     println!("class: {}, subclass: {}", subclass.class().name(), subclass.name());
+/// }
 /// ```
-pub type Bias = UsbId<BIAS_TAG, u8>;
+#[cfg(not(feature = "vendors-only"))]
+pub struct SubClasses;
+#[cfg(not(feature = "vendors-only"))]
+impl SubClasses {
+    /// Returns an iterator over all subclasses in the USB database.
+    pub fn iter() -> SubClassIter {
+        SubClassIter::new(Classes::iter(), |c| c.sub_classes, SUBCLASS_COUNT)
+    }
+}
 
-/// Represents physical descriptor item type in the USB database.
+/// An abstraction for iterating over all protocols in the USB database.
 ///
 /// ```
-/// use usb_ids::{Phy, FromId};
-/// let phy = Phy::from_id(0x25).unwrap();
-/// assert_eq!(phy.name(), "Fifth Toe");
+/// use usb_ids::Protocols;
+///
+/// for protocol in Protocols::iter() {
+///     println!("protocol: {}", protocol.name());
+/// }
 /// ```
-pub type Phy = UsbId<PHY_TAG, u8>;
+#[cfg(not(feature = "vendors-only"))]
+pub struct Protocols;
+#[cfg(not(feature = "vendors-only"))]
+impl Protocols {
+    /// Returns an iterator over all protocols in the USB database.
+    pub fn iter() -> ProtocolIter {
+        ProtocolIter::new(SubClasses::iter(), |s| s.protocols, PROTOCOL_COUNT)
+    }
+}
 
-/// Represents a language type in the USB database.
-///
-/// Languages have a language ID, a pretty name, and a list of associated
-/// [`Dialect`]s.
+/// An abstraction for iterating over all languages in the USB database.
 ///
 /// ```
-/// use usb_ids::{Language, FromId};
-/// let language = Language::from_id(0x000c).unwrap();
-/// assert_eq!(language.name(), "French");
-///
-/// for dialect in language.dialects() {
-///   println!("dialect: {}", dialect.name());
+/// use usb_ids::Languages;
+/// for language in Languages::iter() {
+///    println!("language: {}", language.name());
+///    for dialect in language.dialects() {
+///         println!("\tdialect: {}", dialect.name());
+///    }
 /// }
 /// ```
-pub type Language = UsbIdWithChildren<u16, Dialect>;
+#[cfg(not(feature = "no-languages"))]
+pub struct Languages;
+#[cfg(not(feature = "no-languages"))]
+impl Languages {
+    /// Returns an iterator over all languages in the USB database.
+    pub fn iter() -> LanguageIter {
+        MapIter(USB_LANGS.values())
+    }
 
-impl Language {
-    /// Returns an iterator over the language's [`Dialect`]s.
-    pub fn dialects(&self) -> impl Iterator<Item = &'static Dialect> {
-        self.children()
+    /// Returns an iterator over all languages in the USB database, sorted by name and then
+    /// by ID.
+    #[cfg(feature = "alloc")]
+    pub fn iter_sorted_by_name() -> alloc::vec::IntoIter<&'static Language> {
+        sorted_by_key(Self::iter(), |l| (l.name(), l.id()))
     }
 }
 
-/// Represents a language dialect in the USB database.
+/// An abstraction for iterating over all dialects in the USB database.
 ///
 /// ```
-/// use usb_ids::{Dialect, Language, FromId};
-/// let lang = Language::from_id(0x0007).unwrap();
+/// use usb_ids::Dialects;
 ///
-/// println!("language: {}", lang.name());
-/// for dialect in lang.dialects() {
-///    println!("\tdialect: {}", dialect.name());
+/// for dialect in Dialects::iter() {
+///     println!("dialect: {}", dialect.name());
 /// }
 /// ```
-pub type Dialect = UsbId<DIALECT_TAG, u8>;
+#[cfg(not(feature = "no-languages"))]
+pub struct Dialects;
+#[cfg(not(feature = "no-languages"))]
+impl Dialects {
+    /// Returns an iterator over all dialects in the USB database.
+    pub fn iter() -> DialectIter {
+        DialectIter::new(Languages::iter(), |l| l.children, DIALECT_COUNT)
+    }
+}
 
-impl Dialect {
-    /// Returns the [`Dialect`] corresponding to the given language and dialect IDs,
-    /// or `None` if no such dialect exists in the DB.
-    ///
-    /// ```
-    /// use usb_ids::Dialect;
-    /// let dialect = Dialect::from_lid_did(0x0007, 0x02).unwrap();
-    /// assert_eq!(dialect.name(), "Swiss");
-    /// ```
-    pub fn from_lid_did(language_id: u16, id: u8) -> Option<&'static Self> {
-        let language = Language::from_id(language_id)?;
+/// An abstraction for iterating over all HID usage pages in the USB database.
+///
+/// ```
+/// use usb_ids::HidUsagePages;
+///
+/// for page in HidUsagePages::iter() {
+///     println!("page: {}", page.name());
+///     for usage in page.usages() {
+///         println!("\tusage: {}", usage.name());
+///     }
+/// }
+/// ```
+#[cfg(not(feature = "no-hid"))]
+pub struct HidUsagePages;
+#[cfg(not(feature = "no-hid"))]
+impl HidUsagePages {
+    /// Returns an iterator over all HID usage pages in the USB database.
+    pub fn iter() -> HidUsagePageIter {
+        MapIter(USB_HUTS.values())
+    }
 
-        language.children().find(|d| d.id() == id)
+    /// Returns an iterator over all HID usage pages in the USB database, sorted by ID.
+    #[cfg(feature = "alloc")]
+    pub fn iter_sorted_by_id() -> alloc::vec::IntoIter<&'static HidUsagePage> {
+        sorted_by_key(Self::iter(), |p| p.id())
     }
 }
 
-/// Represents a HID descriptor country code in the USB database.
+/// An abstraction for iterating over all audio terminal types in the USB database.
 ///
 /// ```
-/// use usb_ids::{HidCountryCode, FromId};
-/// let hid_country_code = HidCountryCode::from_id(0x29).unwrap();
-/// assert_eq!(hid_country_code.name(), "Switzerland");
+/// use usb_ids::AudioTerminals;
+///
+/// for audio_terminal in AudioTerminals::iter() {
+///     println!("audio terminal: {}", audio_terminal.name());
+/// }
 /// ```
-pub type HidCountryCode = UsbId<HCC_TAG, u8>;
+#[cfg(not(feature = "no-audio"))]
+pub struct AudioTerminals;
+#[cfg(not(feature = "no-audio"))]
+impl AudioTerminals {
+    /// Returns an iterator over all audio terminal types in the USB database.
+    pub fn iter() -> AudioTerminalIter {
+        MapIter(USB_AUDIO_TERMINALS.values())
+    }
+}
 
-/// Represents a video class terminal type in the USB database.
+/// An abstraction for iterating over all HID descriptor types in the USB database.
 ///
 /// ```
-/// use usb_ids::{VideoTerminal, FromId};
-/// let video_terminal = VideoTerminal::from_id(0x0101).unwrap();
-/// assert_eq!(video_terminal.name(), "USB Streaming");
+/// use usb_ids::Hids;
+///
+/// for hid in Hids::iter() {
+///     println!("HID descriptor type: {}", hid.name());
+/// }
 /// ```
-pub type VideoTerminal = UsbId<VT_TAG, u16>;
+#[cfg(not(feature = "no-hid"))]
+pub struct Hids;
+#[cfg(not(feature = "no-hid"))]
+impl Hids {
+    /// Returns an iterator over all HID descriptor types in the USB database.
+    pub fn iter() -> HidIter {
+        MapIter(USB_HID_IDS.values())
+    }
+}
 
-/// A convenience trait for retrieving a top-level entity (like a [`Vendor`]) from the USB
-/// database by its unique ID.
+/// An abstraction for iterating over all HID descriptor item types in the USB database.
 ///
 /// ```
-/// use usb_ids::{FromId, Vendor};
-/// let vendor = Vendor::from_id(0x1d6b).unwrap();
-/// assert_eq!(vendor.name(), "Linux Foundation");
+/// use usb_ids::HidItemTypes;
+///
+/// for hid_item_type in HidItemTypes::iter() {
+///     println!("HID descriptor item type: {}", hid_item_type.name());
+/// }
 /// ```
-pub trait FromId<T> {
-    /// Returns the entity corresponding to `id`, or `None` if none exists.
-    fn from_id(id: T) -> Option<&'static Self>;
+#[cfg(not(feature = "no-hid"))]
+pub struct HidItemTypes;
+#[cfg(not(feature = "no-hid"))]
+impl HidItemTypes {
+    /// Returns an iterator over all HID descriptor item types in the USB database.
+    pub fn iter() -> HidItemTypeIter {
+        MapIter(USB_HID_R_TYPES.values())
+    }
 }
 
-impl FromId<u16> for Vendor {
-    fn from_id(id: u16) -> Option<&'static Self> {
-        USB_IDS.get(&id)
+/// An abstraction for iterating over all physical descriptor bias types in the USB database.
+///
+/// ```
+/// use usb_ids::Biases;
+///
+/// for bias in Biases::iter() {
+///     println!("bias: {}", bias.name());
+/// }
+/// ```
+#[cfg(not(feature = "no-hid"))]
+pub struct Biases;
+#[cfg(not(feature = "no-hid"))]
+impl Biases {
+    /// Returns an iterator over all physical descriptor bias types in the USB database.
+    pub fn iter() -> BiasIter {
+        MapIter(USB_BIASES.values())
     }
 }
 
-impl FromId<u8> for Class {
-    fn from_id(id: u8) -> Option<&'static Self> {
-        USB_CLASSES.get(&id)
+/// An abstraction for iterating over all physical descriptor item types in the USB database.
+///
+/// ```
+/// use usb_ids::Phys;
+///
+/// for phy in Phys::iter() {
+///     println!("phy: {}", phy.name());
+/// }
+/// ```
+#[cfg(not(feature = "no-hid"))]
+pub struct Phys;
+#[cfg(not(feature = "no-hid"))]
+impl Phys {
+    /// Returns an iterator over all physical descriptor item types in the USB database.
+    pub fn iter() -> PhyIter {
+        MapIter(USB_PHYS.values())
     }
 }
 
-impl FromId<u16> for AudioTerminal {
-    fn from_id(id: u16) -> Option<&'static Self> {
-        USB_AUDIO_TERMINALS.get(&id)
+/// An abstraction for iterating over all HID descriptor country codes in the USB database.
+///
+/// ```
+/// use usb_ids::HidCountryCodes;
+///
+/// for hid_country_code in HidCountryCodes::iter() {
+///     println!("country code: {}", hid_country_code.name());
+/// }
+/// ```
+#[cfg(not(feature = "no-hid"))]
+pub struct HidCountryCodes;
+#[cfg(not(feature = "no-hid"))]
+impl HidCountryCodes {
+    /// Returns an iterator over all HID descriptor country codes in the USB database.
+    pub fn iter() -> HidCountryCodeIter {
+        MapIter(USB_HID_CCS.values())
     }
 }
 
-impl FromId<u8> for Hid {
-    fn from_id(id: u8) -> Option<&'static Self> {
-        USB_HID_IDS.get(&id)
+/// An abstraction for iterating over all video class terminal types in the USB database.
+///
+/// ```
+/// use usb_ids::VideoTerminals;
+///
+/// for video_terminal in VideoTerminals::iter() {
+///     println!("video terminal: {}", video_terminal.name());
+/// }
+/// ```
+#[cfg(not(feature = "no-video"))]
+pub struct VideoTerminals;
+#[cfg(not(feature = "no-video"))]
+impl VideoTerminals {
+    /// Returns an iterator over all video class terminal types in the USB database.
+    pub fn iter() -> VideoTerminalIter {
+        MapIter(USB_VIDEO_TERMINALS.values())
     }
 }
 
-impl FromId<u8> for HidItemType {
-    fn from_id(id: u8) -> Option<&'static Self> {
-        USB_HID_R_TYPES.get(&id)
+/// A single entry point for every lookup and iterator in the USB database.
+///
+/// Each method delegates to the corresponding type, e.g. [`Database::vendor`] is
+/// [`Vendor::from_id`], and [`Database::all_vendors`] is [`Vendors::iter`].
+///
+/// ```
+/// use usb_ids::Database;
+///
+/// let device = Database::device(0x1d6b, 0x0003).unwrap();
+/// assert_eq!(device.name(), "3.0 root hub");
+/// assert_eq!(Database::vendor(0x1d6b), Some(device.vendor()));
+/// assert!(Database::all_vendors().any(|v| v.name() == "Linux Foundation"));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Database;
+impl Database {
+    /// Returns the [`Vendor`] with the given ID, or `None` if it doesn't exist.
+    pub fn vendor(id: u16) -> Option<&'static Vendor> {
+        Vendor::from_id(id)
     }
-}
 
-impl FromId<u8> for HidUsagePage {
-    fn from_id(id: u8) -> Option<&'static Self> {
-        USB_HUTS.get(&id)
+    /// Returns the [`Device`] with the given vendor and product IDs, or `None` if it doesn't exist.
+    pub fn device(vid: u16, pid: u16) -> Option<&'static Device> {
+        Device::from_vid_pid(vid, pid)
     }
-}
 
-impl FromId<u8> for Bias {
-    fn from_id(id: u8) -> Option<&'static Self> {
-        USB_BIASES.get(&id)
+    /// Returns the [`Class`] with the given ID, or `None` if it doesn't exist.
+    #[cfg(not(feature = "vendors-only"))]
+    pub fn class(id: u8) -> Option<&'static Class> {
+        Class::from_id(id)
     }
-}
 
-impl FromId<u8> for Phy {
-    fn from_id(id: u8) -> Option<&'static Self> {
-        USB_PHYS.get(&id)
+    /// Returns the [`SubClass`] with the given class and subclass IDs, or `None` if it doesn't exist.
+    #[cfg(not(feature = "vendors-only"))]
+    pub fn subclass(class_id: u8, id: u8) -> Option<&'static SubClass> {
+        SubClass::from_cid_scid(class_id, id)
     }
-}
 
-impl FromId<u16> for Language {
-    fn from_id(id: u16) -> Option<&'static Self> {
-        USB_LANGS.get(&id)
+    /// Returns the [`Protocol`] with the given class, subclass, and protocol IDs, or `None` if it doesn't exist.
+    #[cfg(not(feature = "vendors-only"))]
+    pub fn protocol(class_id: u8, subclass_id: u8, id: u8) -> Option<&'static Protocol> {
+        Protocol::from_cid_scid_pid(class_id, subclass_id, id)
     }
-}
 
-impl FromId<u8> for HidCountryCode {
-    fn from_id(id: u8) -> Option<&'static Self> {
-        USB_HID_CCS.get(&id)
+    /// Returns the [`AudioTerminal`] with the given ID, or `None` if it doesn't exist.
+    #[cfg(not(feature = "no-audio"))]
+    pub fn audio_terminal(id: u16) -> Option<&'static AudioTerminal> {
+        AudioTerminal::from_id(id)
+    }
+
+    /// Returns the [`Hid`] descriptor type with the given ID, or `None` if it doesn't exist.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn hid(id: u8) -> Option<&'static Hid> {
+        Hid::from_id(id)
+    }
+
+    /// Returns the [`HidItemType`] with the given ID, or `None` if it doesn't exist.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn hid_item_type(id: u8) -> Option<&'static HidItemType> {
+        HidItemType::from_id(id)
+    }
+
+    /// Returns the [`HidUsagePage`] with the given ID, or `None` if it doesn't exist.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn hid_usage_page(id: u8) -> Option<&'static HidUsagePage> {
+        HidUsagePage::from_id(id)
     }
+
+    /// Returns the [`HidUsage`] with the given usage page and usage IDs, or `None` if it doesn't exist.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn hid_usage(page_id: u8, id: u16) -> Option<&'static HidUsage> {
+        HidUsage::from_pageid_uid(page_id, id)
+    }
+
+    /// Returns the [`Bias`] with the given ID, or `None` if it doesn't exist.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn bias(id: u8) -> Option<&'static Bias> {
+        Bias::from_id(id)
+    }
+
+    /// Returns the [`Phy`] with the given ID, or `None` if it doesn't exist.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn phy(id: u8) -> Option<&'static Phy> {
+        Phy::from_id(id)
+    }
+
+    /// Returns the [`Language`] with the given ID, or `None` if it doesn't exist.
+    #[cfg(not(feature = "no-languages"))]
+    pub fn language(id: u16) -> Option<&'static Language> {
+        Language::from_id(id)
+    }
+
+    /// Returns the [`Dialect`] with the given language and dialect IDs, or `None` if it doesn't exist.
+    #[cfg(not(feature = "no-languages"))]
+    pub fn dialect(language_id: u16, id: u8) -> Option<&'static Dialect> {
+        Dialect::from_lid_did(language_id, id)
+    }
+
+    /// Returns the [`HidCountryCode`] with the given ID, or `None` if it doesn't exist.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn hid_country_code(id: u8) -> Option<&'static HidCountryCode> {
+        HidCountryCode::from_id(id)
+    }
+
+    /// Returns the [`VideoTerminal`] with the given ID, or `None` if it doesn't exist.
+    #[cfg(not(feature = "no-video"))]
+    pub fn video_terminal(id: u16) -> Option<&'static VideoTerminal> {
+        VideoTerminal::from_id(id)
+    }
+
+    /// Returns an iterator over all vendors in the USB database.
+    pub fn all_vendors() -> VendorIter {
+        Vendors::iter()
+    }
+
+    /// Returns an iterator over all devices in the USB database.
+    pub fn all_devices() -> DeviceIter {
+        Devices::iter()
+    }
+
+    /// Returns an iterator over all classes in the USB database.
+    #[cfg(not(feature = "vendors-only"))]
+    pub fn all_classes() -> ClassIter {
+        Classes::iter()
+    }
+
+    /// Returns an iterator over all subclasses in the USB database.
+    #[cfg(not(feature = "vendors-only"))]
+    pub fn all_subclasses() -> SubClassIter {
+        SubClasses::iter()
+    }
+
+    /// Returns an iterator over all protocols in the USB database.
+    #[cfg(not(feature = "vendors-only"))]
+    pub fn all_protocols() -> ProtocolIter {
+        Protocols::iter()
+    }
+
+    /// Returns an iterator over all audio terminal types in the USB database.
+    #[cfg(not(feature = "no-audio"))]
+    pub fn all_audio_terminals() -> AudioTerminalIter {
+        AudioTerminals::iter()
+    }
+
+    /// Returns an iterator over all HID descriptor types in the USB database.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn all_hids() -> HidIter {
+        Hids::iter()
+    }
+
+    /// Returns an iterator over all HID item types in the USB database.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn all_hid_item_types() -> HidItemTypeIter {
+        HidItemTypes::iter()
+    }
+
+    /// Returns an iterator over all HID usage pages in the USB database.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn all_hid_usage_pages() -> HidUsagePageIter {
+        HidUsagePages::iter()
+    }
+
+    /// Returns an iterator over all HID usages in the USB database.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn all_hid_usages() -> HidUsageIter {
+        all_hid_usages()
+    }
+
+    /// Returns an iterator over all biases in the USB database.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn all_biases() -> BiasIter {
+        Biases::iter()
+    }
+
+    /// Returns an iterator over all physical descriptor items in the USB database.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn all_phys() -> PhyIter {
+        Phys::iter()
+    }
+
+    /// Returns an iterator over all languages in the USB database.
+    #[cfg(not(feature = "no-languages"))]
+    pub fn all_languages() -> LanguageIter {
+        Languages::iter()
+    }
+
+    /// Returns an iterator over all dialects in the USB database.
+    #[cfg(not(feature = "no-languages"))]
+    pub fn all_dialects() -> DialectIter {
+        Dialects::iter()
+    }
+
+    /// Returns an iterator over all HID country codes in the USB database.
+    #[cfg(not(feature = "no-hid"))]
+    pub fn all_hid_country_codes() -> HidCountryCodeIter {
+        HidCountryCodes::iter()
+    }
+
+    /// Returns an iterator over all video class terminal types in the USB database.
+    #[cfg(not(feature = "no-video"))]
+    pub fn all_video_terminals() -> VideoTerminalIter {
+        VideoTerminals::iter()
+    }
+}
+
+/// Represents a USB device vendor in the USB database.
+///
+/// Every device vendor has a vendor ID, a pretty name, and a
+/// list of associated [`Device`]s.
+///
+/// `Vendor` deliberately doesn't implement [`Borrow<u16>`](core::borrow::Borrow): vendors
+/// are ordered by name (see the [`Ord`] impl), so borrowing one as its ID would break
+/// lookups in ordered collections like `BTreeSet`. To look vendors up by ID in a
+/// collection, build it from [`Vendors::iter_with_ids`] instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Vendor {
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    id: u16,
+    name: &'static str,
+    devices: &'static [Device],
 }
 
-impl FromId<u16> for VideoTerminal {
-    fn from_id(id: u16) -> Option<&'static Self> {
-        USB_VIDEO_TERMINALS.get(&id)
+impl Vendor {
+    /// Returns the [`Vendor`] with exactly the given name, or `None` if no such vendor
+    /// exists in the DB.
+    ///
+    /// Looking up a vendor by name is expensive (`O(n)`) unless the `name-index`
+    /// feature is enabled; prefer [`FromId::from_id`] when the vendor's ID is known.
+    ///
+    /// ```
+    /// use usb_ids::Vendor;
+    /// let vendor = Vendor::from_name("Linux Foundation").unwrap();
+    /// assert_eq!(vendor.id(), 0x1d6b);
+    /// ```
+    pub fn from_name(name: &str) -> Option<&'static Vendor> {
+        static INDEX: NameIndex<Vendor> = NameIndex::new();
+        INDEX.find(Vendors::iter(), name)
+    }
+
+    /// Like [`Vendor::from_name`], but compares names case-insensitively (ASCII only).
+    ///
+    /// Looking up a vendor by name is expensive (`O(n)`).
+    ///
+    /// ```
+    /// use usb_ids::Vendor;
+    /// let vendor = Vendor::from_name_ignore_case("linux foundation").unwrap();
+    /// assert_eq!(vendor.id(), 0x1d6b);
+    /// ```
+    pub fn from_name_ignore_case(name: &str) -> Option<&'static Vendor> {
+        Vendors::iter().find(|v| v.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns an iterator over every [`Vendor`] whose name contains `query`.
+    ///
+    /// The search is case-sensitive; callers wanting a case-insensitive search should
+    /// normalize case themselves. Searching is expensive (`O(n)` in the number of vendors).
+    ///
+    /// ```
+    /// use usb_ids::Vendor;
+    /// assert!(Vendor::search_by_name("Texas Instruments").any(|v| v.id() == 0x0451));
+    /// ```
+    pub fn search_by_name(query: &str) -> impl Iterator<Item = &'static Vendor> + '_ {
+        Vendors::iter().filter(move |v| v.name().contains(query))
+    }
+
+    /// Returns an iterator over every [`Vendor`] whose name matches the given regular
+    /// expression, or an error if the expression is invalid.
+    ///
+    /// Like [`Vendor::search_by_name`], this is expensive (`O(n)` in the number of vendors).
+    ///
+    /// ```
+    /// use usb_ids::Vendor;
+    /// let mut vendors = Vendor::search_by_regex("^Linux Found").unwrap();
+    /// assert!(vendors.any(|v| v.id() == 0x1d6b));
+    ///
+    /// assert!(Vendor::search_by_regex("(unclosed").is_err());
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn search_by_regex(
+        pattern: &str,
+    ) -> Result<impl Iterator<Item = &'static Vendor>, regex::Error> {
+        let regex = regex::Regex::new(pattern)?;
+        Ok(Vendors::iter().filter(move |v| regex.is_match(v.name())))
+    }
+
+    /// Returns the vendor's ID.
+    pub fn id(&self) -> u16 {
+        self.id
+    }
+
+    /// Returns the vendor's ID as a `0x`-prefixed, zero-padded hex string.
+    ///
+    /// ```
+    /// use usb_ids::{FromId, Vendor};
+    /// let vendor = Vendor::from_id(0x1d6b).unwrap();
+    /// assert_eq!(vendor.id_as_hex(), "0x1d6b");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn id_as_hex(&self) -> alloc::string::String {
+        alloc::format!("{:#06x}", self.id)
+    }
+
+    /// Returns the vendor's name.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns an iterator over the vendor's [`Device`]s.
+    pub fn devices(&self) -> DeviceIter {
+        DeviceIter::from_slice(self.devices)
+    }
+
+    /// Returns an iterator over the vendor's [`Device`]s that have at least one
+    /// [`Interface`].
+    ///
+    /// Very few devices in the database have interfaces, so this is usually much shorter
+    /// than [`Vendor::devices`].
+    pub fn devices_with_interfaces(&self) -> impl Iterator<Item = &'static Device> {
+        self.devices.iter().filter(|d| !d.interfaces.is_empty())
+    }
+
+    /// Returns the vendor's [`Device`] with exactly the given name, or `None` if the vendor
+    /// has no such device.
+    ///
+    /// This is expensive (`O(n)` in the vendor's devices); prefer [`Device::from_vid_pid`]
+    /// when the device's ID is known.
+    ///
+    /// ```
+    /// use usb_ids::{FromId, Vendor};
+    /// let vendor = Vendor::from_id(0x1d6b).unwrap();
+    /// assert_eq!(vendor.device_by_name("3.0 root hub").unwrap().id(), 0x0003);
+    /// ```
+    pub fn device_by_name(&self, name: &str) -> Option<&'static Device> {
+        self.devices.iter().find(|d| d.name == name)
+    }
+
+    /// Like [`Vendor::device_by_name`], but compares names case-insensitively (ASCII only).
+    pub fn device_by_name_ci(&self, name: &str) -> Option<&'static Device> {
+        self.devices
+            .iter()
+            .find(|d| d.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the vendor's first [`Device`] matching the given predicate, or `None` if no
+    /// device matches.
+    ///
+    /// This is a convenience wrapper around `self.devices().find(...)`, and is `O(n)` in the
+    /// vendor's devices; prefer [`Device::from_vid_pid`] when the device's ID is known.
+    ///
+    /// ```
+    /// use usb_ids::{FromId, Vendor};
+    /// let vendor = Vendor::from_id(0x1d6b).unwrap();
+    /// let device = vendor.find_device(|d| d.name().starts_with("3.0")).unwrap();
+    /// assert_eq!(device.id(), 0x0003);
+    /// ```
+    pub fn find_device(&self, mut pred: impl FnMut(&Device) -> bool) -> Option<&'static Device> {
+        self.devices.iter().find(|d| pred(d))
+    }
+
+    /// Returns the vendor's [`Device`] with the given product ID, or `None` if the vendor
+    /// has no such device.
+    ///
+    /// This is equivalent to [`Device::from_vid_pid`] with the vendor's ID, and is
+    /// similarly cheap (`O(1)`).
+    ///
+    /// ```
+    /// use usb_ids::{FromId, Vendor};
+    /// let vendor = Vendor::from_id(0x1d6b).unwrap();
+    /// assert_eq!(vendor.get_device(0x0003).unwrap().name(), "3.0 root hub");
+    /// assert!(vendor.get_device(0xfffe).is_none());
+    /// ```
+    pub fn get_device(&self, pid: u16) -> Option<&'static Device> {
+        Device::from_vid_pid(self.id, pid)
+    }
+
+    /// Returns `true` if the vendor has a [`Device`] with the given product ID.
+    ///
+    /// This is `O(n)` in the vendor's devices.
+    pub fn has_device(&self, pid: u16) -> bool {
+        self.devices.iter().any(|d| d.id == pid)
+    }
+
+    /// Returns an iterator over the vendor's devices' product IDs.
+    pub fn device_ids(&self) -> impl ExactSizeIterator<Item = u16> {
+        self.devices.iter().map(|d| d.id)
+    }
+
+    /// Returns an iterator over the vendor's devices' names.
+    pub fn device_names(&self) -> impl ExactSizeIterator<Item = &'static str> {
+        self.devices.iter().map(|d| d.name)
+    }
+
+    /// Returns the number of devices belonging to the vendor.
+    ///
+    /// Unlike counting via the iterator, this is cheap (`O(1)`).
+    pub fn device_count(&self) -> usize {
+        self.devices.len()
+    }
+
+    /// Returns `true` if the vendor has no devices.
+    pub fn is_empty(&self) -> bool {
+        self.devices.is_empty()
+    }
+
+    /// Returns the vendor's [`Device`]s, sorted by name and then by ID, e.g. for display.
+    #[cfg(feature = "alloc")]
+    pub fn devices_sorted_by_name(&self) -> alloc::vec::Vec<&'static Device> {
+        let mut devices: alloc::vec::Vec<_> = self.devices.iter().collect();
+        devices.sort_unstable_by_key(|d| (d.name(), d.id()));
+        devices
+    }
+
+    /// Returns the vendor's [`Device`]s, sorted by ID, e.g. for binary searching.
+    ///
+    /// ```
+    /// use usb_ids::{FromId, Vendor};
+    /// let devices = Vendor::from_id(0x1d6b).unwrap().devices_sorted_by_id();
+    /// let index = devices.binary_search_by_key(&0x0003, |d| d.id()).unwrap();
+    /// assert_eq!(devices[index].name(), "3.0 root hub");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn devices_sorted_by_id(&self) -> alloc::vec::Vec<&'static Device> {
+        let mut devices: alloc::vec::Vec<_> = self.devices.iter().collect();
+        devices.sort_unstable_by_key(|d| d.id());
+        devices
+    }
+}
+
+impl fmt::Display for Vendor {
+    /// Formats the vendor as `name (id)`, e.g. `Linux Foundation (0x1d6b)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({:#06x})", self.name, self.id)
+    }
+}
+
+impl fmt::LowerHex for Vendor {
+    /// Formats the vendor's ID as zero-padded lowercase hex, e.g. `1d6b`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04x}", self.id)
+    }
+}
+
+impl fmt::UpperHex for Vendor {
+    /// Formats the vendor's ID as zero-padded uppercase hex, e.g. `1D6B`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04X}", self.id)
+    }
+}
+
+impl Hash for Vendor {
+    /// Hashes only the vendor's ID, which uniquely identifies it.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl PartialEq<u16> for Vendor {
+    /// Compares the vendor's ID.
+    fn eq(&self, other: &u16) -> bool {
+        self.id == *other
+    }
+}
+
+impl PartialEq<&str> for Vendor {
+    /// Compares the vendor's name, exactly.
+    fn eq(&self, other: &&str) -> bool {
+        self.name == *other
+    }
+}
+
+impl PartialOrd for Vendor {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Vendor {
+    /// Orders vendors alphabetically by name, then by ID.
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.name, self.id).cmp(&(other.name, other.id))
+    }
+}
+
+/// Represents a single device in the USB database.
+///
+/// Every device has a corresponding vendor, a device ID, a pretty name,
+/// and a list of associated [`Interface`]s.
+///
+/// Like [`Vendor`], `Device` doesn't implement [`Borrow<(u16, u16)>`](core::borrow::Borrow),
+/// which would mean storing every device's IDs a second time as a pair; build a collection
+/// from [`Devices::iter_with_ids`] to look devices up by ID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Device {
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    vendor_id: u16,
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    id: u16,
+    name: &'static str,
+    interfaces: &'static [Interface],
+}
+
+impl Device {
+    /// Returns the [`Device`] corresponding to the given vendor and product IDs,
+    /// or `None` if no such device exists in the DB.
+    ///
+    /// Looking up a device by vendor and product IDs is cheap (`O(1)`).
+    ///
+    /// ```
+    /// use usb_ids::Device;
+    /// let device = Device::from_vid_pid(0x1d6b, 0x0003).unwrap();
+    /// assert_eq!(device.name(), "3.0 root hub");
+    /// ```
+    pub fn from_vid_pid(vid: u16, pid: u16) -> Option<&'static Device> {
+        USB_DEVICES.get(&((vid as u32) << 16 | pid as u32))
+    }
+
+    /// Like [`Device::from_vid_pid`], but takes strongly typed IDs so that the vendor and
+    /// device IDs can't be swapped.
+    pub fn from_ids(vid: VendorId, pid: ProductId) -> Option<&'static Device> {
+        Device::from_vid_pid(vid.0, pid.0)
+    }
+
+    /// Like [`Device::from_vid_pid`], but distinguishes between an unknown vendor
+    /// and an unknown device of a known vendor.
+    ///
+    /// ```
+    /// use usb_ids::{Device, UsbIdError};
+    /// assert!(Device::try_from_vid_pid(0x1d6b, 0x0003).is_ok());
+    /// assert_eq!(
+    ///     Device::try_from_vid_pid(0x1d6b, 0x9999),
+    ///     Err(UsbIdError::DeviceNotFound { vid: 0x1d6b, pid: 0x9999 })
+    /// );
+    /// ```
+    pub fn try_from_vid_pid(vid: u16, pid: u16) -> Result<&'static Device, UsbIdError> {
+        match Device::from_vid_pid(vid, pid) {
+            Some(device) => Ok(device),
+            None if Vendor::from_id(vid).is_none() => Err(UsbIdError::VendorNotFound(vid)),
+            None => Err(UsbIdError::DeviceNotFound { vid, pid }),
+        }
+    }
+
+    /// Returns an iterator over every [`Device`], across all vendors, whose name contains `query`.
+    ///
+    /// The search is case-sensitive; callers wanting a case-insensitive search should
+    /// normalize case themselves. Searching is expensive (`O(n)` in the number of devices).
+    ///
+    /// ```
+    /// use usb_ids::Device;
+    /// assert!(Device::search_by_name("root hub").any(|d| d.as_vid_pid() == (0x1d6b, 0x0003)));
+    /// ```
+    pub fn search_by_name(query: &str) -> impl Iterator<Item = &'static Device> + '_ {
+        Devices::iter().filter(move |d| d.name().contains(query))
+    }
+
+    /// Returns an iterator over every [`Device`], across all vendors, whose name matches the
+    /// given regular expression, or an error if the expression is invalid.
+    ///
+    /// Like [`Device::search_by_name`], this is expensive (`O(n)` in the number of devices).
+    #[cfg(feature = "regex")]
+    pub fn search_by_regex(
+        pattern: &str,
+    ) -> Result<impl Iterator<Item = &'static Device>, regex::Error> {
+        let regex = regex::Regex::new(pattern)?;
+        Ok(Devices::iter().filter(move |d| regex.is_match(d.name())))
+    }
+
+    /// Returns the [`Vendor`] that this device belongs to.
+    ///
+    /// Looking up a vendor by device is cheap (`O(1)`).
+    pub fn vendor(&self) -> &'static Vendor {
+        USB_IDS.get(&self.vendor_id).unwrap()
+    }
+
+    /// Returns the device's full path, as `vendor / device`.
+    ///
+    /// ```
+    /// use usb_ids::Device;
+    /// let device = Device::from_vid_pid(0x1d6b, 0x0003).unwrap();
+    /// assert_eq!(device.full_path(), "Linux Foundation / 3.0 root hub");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn full_path(&self) -> alloc::string::String {
+        alloc::format!("{} / {}", self.vendor().name, self.name)
+    }
+
+    /// Returns a tuple of (vendor id, device/"product" id) for this device.
+    ///
+    /// This is convenient for interactions with other USB libraries.
+    pub fn as_vid_pid(&self) -> (u16, u16) {
+        (self.vendor_id, self.id)
+    }
+
+    /// Returns the device's vendor and product IDs as `vid:pid`, in zero-padded hex without
+    /// a `0x` prefix.
+    ///
+    /// This is the same format `lsusb` uses (and that [`VidPid`] displays as), so it can be
+    /// matched against its output directly.
+    ///
+    /// ```
+    /// use usb_ids::Device;
+    /// let device = Device::from_vid_pid(0x1d6b, 0x0003).unwrap();
+    /// assert_eq!(device.as_vid_pid_hex(), "1d6b:0003");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn as_vid_pid_hex(&self) -> alloc::string::String {
+        alloc::format!("{:04x}:{:04x}", self.vendor_id, self.id)
+    }
+
+    /// Returns the device's ID.
+    pub fn id(&self) -> u16 {
+        self.id
+    }
+
+    /// Returns the device's name.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns an iterator over the device's [`Interface`]s.
+    ///
+    /// **NOTE**: The USB database does not include interface information for
+    /// most devices. This list is not authoritative.
+    pub fn interfaces(&self) -> InterfaceIter {
+        InterfaceIter::from_slice(self.interfaces)
+    }
+}
+
+impl fmt::Display for Device {
+    /// Formats the device as `vendor device (vid:pid)`, e.g.
+    /// `Linux Foundation 3.0 root hub (0x1d6b:0x0003)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} ({:#06x}:{:#06x})",
+            self.vendor().name,
+            self.name,
+            self.vendor_id,
+            self.id
+        )
+    }
+}
+
+impl fmt::LowerHex for Device {
+    /// Formats the device's IDs as zero-padded lowercase `vid:pid`, e.g. `1d6b:0003`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04x}:{:04x}", self.vendor_id, self.id)
+    }
+}
+
+impl fmt::UpperHex for Device {
+    /// Formats the device's IDs as zero-padded uppercase `vid:pid`, e.g. `1D6B:0003`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04X}:{:04X}", self.vendor_id, self.id)
+    }
+}
+
+impl Hash for Device {
+    /// Hashes only the device's vendor and product IDs, which uniquely identify it.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.vendor_id, self.id).hash(state);
+    }
+}
+
+impl PartialEq<(u16, u16)> for Device {
+    /// Compares the device's `(vendor ID, product ID)` pair.
+    fn eq(&self, other: &(u16, u16)) -> bool {
+        self.as_vid_pid() == *other
+    }
+}
+
+impl PartialOrd for Device {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Device {
+    /// Orders devices by vendor ID, then by device ID.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_vid_pid().cmp(&other.as_vid_pid())
+    }
+}
+
+/// Represents an interface to a USB device in the USB database.
+///
+/// Every interface has an interface ID (which is an index on the device)
+/// and a pretty name. Like an interface descriptor, it also has a class, subclass,
+/// and protocol ID, but the database doesn't currently record these, so they're
+/// always `0` (unspecified).
+///
+/// **NOTE**: The USB database is not a canonical or authoritative source
+/// of interface information for devices. Users who wish to discover interfaces
+/// on their USB devices should query those devices directly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Interface {
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    vendor_id: u16,
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    device_id: u16,
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    id: u8,
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    class_id: u8,
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    subclass_id: u8,
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    protocol_id: u8,
+    name: &'static str,
+}
+
+impl Interface {
+    /// Returns the [`Device`] that this interface belongs to.
+    pub fn device(&self) -> &'static Device {
+        Device::from_vid_pid(self.vendor_id, self.device_id).unwrap()
+    }
+
+    /// Returns the [`Vendor`] that this interface's device belongs to.
+    ///
+    /// Looking up a vendor by interface is cheap (`O(1)`).
+    pub fn vendor(&self) -> &'static Vendor {
+        USB_IDS.get(&self.vendor_id).unwrap()
+    }
+
+    /// Returns the interface's ID.
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    /// Returns the interface's class ID (`bInterfaceClass`), or `0` if unspecified.
+    ///
+    /// The USB database has no such data for interfaces, so this is currently always `0`.
+    pub fn class_id(&self) -> u8 {
+        self.class_id
+    }
+
+    /// Returns the interface's subclass ID (`bInterfaceSubClass`), or `0` if unspecified.
+    ///
+    /// The USB database has no such data for interfaces, so this is currently always `0`.
+    pub fn subclass_id(&self) -> u8 {
+        self.subclass_id
+    }
+
+    /// Returns the interface's protocol ID (`bInterfaceProtocol`), or `0` if unspecified.
+    ///
+    /// The USB database has no such data for interfaces, so this is currently always `0`.
+    pub fn protocol_id(&self) -> u8 {
+        self.protocol_id
+    }
+
+    /// Returns the interface's [`Class`], or `None` if its class is unspecified or not in
+    /// the DB.
+    ///
+    /// Since the USB database doesn't record interfaces' classes (see
+    /// [`Interface::class_id`]), this currently always returns `None`.
+    #[cfg(not(feature = "vendors-only"))]
+    pub fn class(&self) -> Option<&'static Class> {
+        match self.class_id {
+            0 => None,
+            id => Class::from_id(id),
+        }
+    }
+
+    /// Returns the interface's [`Protocol`], or `None` if its class is unspecified or the
+    /// protocol is not in the DB.
+    ///
+    /// Since the USB database doesn't record interfaces' classes (see
+    /// [`Interface::class_id`]), this currently always returns `None`.
+    #[cfg(not(feature = "vendors-only"))]
+    pub fn protocol(&self) -> Option<&'static Protocol> {
+        match self.class_id {
+            0 => None,
+            id => Protocol::from_cid_scid_pid(id, self.subclass_id, self.protocol_id),
+        }
+    }
+
+    /// Returns the interface's name.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// Represents a USB device class in the USB database.
+///
+/// Every device class has a class ID, a pretty name, and a
+/// list of associated [`SubClass`]s.
+///
+/// ```
+/// use usb_ids::{Class, Classes, FromId};
+/// let class = Class::from_id(0x03).unwrap();
+/// assert_eq!(class.name(), "Human Interface Device");
+/// ```
+#[cfg(not(feature = "vendors-only"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Class {
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    id: u8,
+    name: &'static str,
+    sub_classes: &'static [SubClass],
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl Class {
+    /// Returns the [`Class`] with exactly the given name, or `None` if no such class
+    /// exists in the DB.
+    ///
+    /// Looking up a class by name is expensive (`O(n)`) unless the `name-index`
+    /// feature is enabled; prefer [`FromId::from_id`] when the ID is known.
+    ///
+    /// ```
+    /// use usb_ids::{Class, FromId};
+    /// let class = Class::from_name("Human Interface Device").unwrap();
+    /// assert_eq!(Class::from_id(class.id()), Some(class));
+    /// ```
+    pub fn from_name(name: &str) -> Option<&'static Class> {
+        static INDEX: NameIndex<Class> = NameIndex::new();
+        INDEX.find(Classes::iter(), name)
+    }
+
+    /// Returns an iterator over every [`Class`] whose name contains `query`.
+    ///
+    /// The search is case-sensitive and expensive (`O(n)`).
+    pub fn search_by_name(query: &str) -> impl Iterator<Item = &'static Class> + '_ {
+        Classes::iter().filter(move |x| x.name().contains(query))
+    }
+
+    /// Returns an iterator over every [`Class`] whose name matches the given regular
+    /// expression, or an error if the expression is invalid.
+    ///
+    /// The search is expensive (`O(n)`).
+    #[cfg(feature = "regex")]
+    pub fn search_by_regex(
+        pattern: &str,
+    ) -> Result<impl Iterator<Item = &'static Class>, regex::Error> {
+        let regex = regex::Regex::new(pattern)?;
+        Ok(Classes::iter().filter(move |x| regex.is_match(x.name())))
+    }
+
+    /// Returns the class's ID.
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    /// Returns the class's ID as a `0x`-prefixed, zero-padded hex string.
+    ///
+    /// ```
+    /// use usb_ids::{Class, FromId};
+    /// let class = Class::from_id(0x03).unwrap();
+    /// assert_eq!(class.id_as_hex(), "0x03");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn id_as_hex(&self) -> alloc::string::String {
+        alloc::format!("{:#04x}", self.id)
+    }
+
+    /// Returns the class's name.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns an iterator over the class's [`SubClass`]s.
+    pub fn sub_classes(&self) -> SubClassIter {
+        SubClassIter::from_slice(self.sub_classes)
+    }
+
+    /// Returns the class's [`SubClass`] with exactly the given name, or `None` if the class
+    /// has no such subclass.
+    ///
+    /// This is expensive (`O(n)` in the class's subclasses).
+    pub fn subclass_by_name(&self, name: &str) -> Option<&'static SubClass> {
+        self.sub_classes.iter().find(|s| s.name == name)
+    }
+
+    /// Returns the class's first [`SubClass`] matching the given predicate, or `None` if no
+    /// subclass matches.
+    ///
+    /// This is `O(n)` in the class's subclasses; prefer [`SubClass::from_cid_scid`] when the
+    /// subclass's ID is known.
+    pub fn find_subclass(
+        &self,
+        mut pred: impl FnMut(&SubClass) -> bool,
+    ) -> Option<&'static SubClass> {
+        self.sub_classes.iter().find(|s| pred(s))
+    }
+
+    /// Returns the class's [`SubClass`] with the given ID, or `None` if the class has no
+    /// such subclass.
+    ///
+    /// This is equivalent to [`SubClass::from_cid_scid`] with the class's ID, and is
+    /// similarly cheap (`O(1)`).
+    pub fn get_subclass(&self, id: u8) -> Option<&'static SubClass> {
+        SubClass::from_cid_scid(self.id, id)
+    }
+
+    /// Returns `true` if the class has a [`SubClass`] with the given ID.
+    ///
+    /// This is `O(n)` in the class's subclasses.
+    pub fn has_subclass(&self, id: u8) -> bool {
+        self.sub_classes.iter().any(|s| s.id == id)
+    }
+
+    /// Returns an iterator over the class's subclasses' IDs.
+    pub fn subclass_ids(&self) -> impl ExactSizeIterator<Item = u8> {
+        self.sub_classes.iter().map(|s| s.id)
+    }
+
+    /// Returns an iterator over the class's subclasses' names.
+    pub fn subclass_names(&self) -> impl ExactSizeIterator<Item = &'static str> {
+        self.sub_classes.iter().map(|s| s.name)
+    }
+
+    /// Returns the number of subclasses belonging to the class.
+    ///
+    /// Unlike counting via the iterator, this is cheap (`O(1)`).
+    pub fn subclass_count(&self) -> usize {
+        self.sub_classes.len()
+    }
+
+    /// Returns `true` if the class has no subclasses.
+    pub fn is_empty(&self) -> bool {
+        self.sub_classes.is_empty()
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl fmt::Display for Class {
+    /// Formats the class as `name (id)`, e.g. `Human Interface Device (0x03)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({:#04x})", self.name, self.id)
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl fmt::LowerHex for Class {
+    /// Formats the class's ID as zero-padded lowercase hex, e.g. `0e`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02x}", self.id)
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl fmt::UpperHex for Class {
+    /// Formats the class's ID as zero-padded uppercase hex, e.g. `0E`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02X}", self.id)
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl PartialEq<&str> for Class {
+    /// Compares the class's name, exactly.
+    fn eq(&self, other: &&str) -> bool {
+        self.name == *other
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl PartialOrd for Class {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl Ord for Class {
+    /// Orders classes by ID.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+/// Represents a class subclass in the USB database. Subclasses are part of the
+/// USB class code triplet (base class, subclass, protocol).
+///
+/// Contained within a [`Class`] and may contain a list of associated
+/// [`Protocol`]s.
+#[cfg(not(feature = "vendors-only"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SubClass {
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    class_id: u8,
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    id: u8,
+    name: &'static str,
+    protocols: &'static [Protocol],
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl SubClass {
+    /// Returns the [`SubClass`] corresponding to the given class and subclass IDs,
+    /// or `None` if no such subclass exists in the DB.
+    ///
+    /// Looking up a subclass by class and subclass IDs is cheap (`O(1)`).
+    ///
+    /// ```
+    /// use usb_ids::SubClass;
+    /// let subclass = SubClass::from_cid_scid(0x02, 0x03).unwrap();
+    /// assert_eq!(subclass.name(), "Telephone");
+    ///
+    /// assert!(SubClass::from_cid_scid(0x3c, 0x02).is_none());
+    /// ```
+    pub fn from_cid_scid(class_id: u8, id: u8) -> Option<&'static Self> {
+        USB_SUBCLASSES.get(&((class_id as u16) << 8 | id as u16))
+    }
+
+    /// Returns the [`SubClass`] with exactly the given name under the given class, or `None`
+    /// if the class doesn't exist or has no such subclass.
+    ///
+    /// This is `O(n)` in the class's subclasses.
+    ///
+    /// ```
+    /// use usb_ids::SubClass;
+    /// let subclass = SubClass::from_name_in_class(0x02, "Telephone").unwrap();
+    /// assert_eq!(subclass.id(), 0x03);
+    /// ```
+    pub fn from_name_in_class(class_id: u8, name: &str) -> Option<&'static Self> {
+        Class::from_id(class_id)?.subclass_by_name(name)
+    }
+
+    /// Like [`SubClass::from_cid_scid`], but distinguishes between an unknown class
+    /// and an unknown subclass of a known class.
+    ///
+    /// ```
+    /// use usb_ids::{SubClass, UsbIdError};
+    /// assert!(SubClass::try_from_cid_scid(0x02, 0x03).is_ok());
+    /// assert_eq!(
+    ///     SubClass::try_from_cid_scid(0x3c, 0x02),
+    ///     Err(UsbIdError::SubClassNotFound { class_id: 0x3c, subclass_id: 0x02 })
+    /// );
+    /// ```
+    pub fn try_from_cid_scid(class_id: u8, id: u8) -> Result<&'static Self, UsbIdError> {
+        match SubClass::from_cid_scid(class_id, id) {
+            Some(subclass) => Ok(subclass),
+            None if Class::from_id(class_id).is_none() => Err(UsbIdError::ClassNotFound(class_id)),
+            None => Err(UsbIdError::SubClassNotFound {
+                class_id,
+                subclass_id: id,
+            }),
+        }
+    }
+
+    /// Returns the [`Class`] that this subclass belongs to.
+    ///
+    /// Looking up a class by subclass is cheap (`O(1)`).
+    ///
+    /// ```
+    /// use usb_ids::SubClass;
+    /// let subclass = SubClass::from_cid_scid(0x02, 0x03).unwrap();
+    /// let class = subclass.class();
+    /// assert_eq!(class.id(), 0x02);
+    /// ```
+    pub fn class(&self) -> &'static Class {
+        USB_CLASSES.get(&self.class_id).unwrap()
+    }
+
+    /// Returns the subclass's full path, as `class / subclass`.
+    ///
+    /// ```
+    /// use usb_ids::SubClass;
+    /// let subclass = SubClass::from_cid_scid(0x02, 0x02).unwrap();
+    /// assert_eq!(subclass.full_path(), "Communications / Abstract (modem)");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn full_path(&self) -> alloc::string::String {
+        alloc::format!("{} / {}", self.class().name, self.name)
+    }
+
+    /// Returns a tuple of (class id, subclass id) for this subclass.
+    ///
+    /// This is convenient for interactions with other USB libraries.
+    pub fn as_cid_scid(&self) -> (u8, u8) {
+        (self.class_id, self.id)
+    }
+
+    /// Returns the subclass' ID.
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    /// Returns the subclass' name.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns an iterator over the subclasses's [`Protocol`]s.
+    ///
+    /// **NOTE**: The USB database nor USB-IF includes protocol information for
+    /// all subclassess. This list is not authoritative.
+    pub fn protocols(&self) -> ProtocolIter {
+        ProtocolIter::from_slice(self.protocols)
+    }
+
+    /// Returns the subclass's [`Protocol`] with exactly the given name, or `None` if the
+    /// subclass has no such protocol.
+    ///
+    /// This is expensive (`O(n)` in the subclass's protocols).
+    pub fn protocol_by_name(&self, name: &str) -> Option<&'static Protocol> {
+        self.protocols.iter().find(|p| p.name == name)
+    }
+
+    /// Returns the subclass's first [`Protocol`] matching the given predicate, or `None` if
+    /// no protocol matches.
+    ///
+    /// This is `O(n)` in the subclass's protocols; prefer [`Protocol::from_cid_scid_pid`]
+    /// when the protocol's ID is known.
+    pub fn find_protocol(
+        &self,
+        mut pred: impl FnMut(&Protocol) -> bool,
+    ) -> Option<&'static Protocol> {
+        self.protocols.iter().find(|p| pred(p))
+    }
+
+    /// Returns the subclass's [`Protocol`] with the given ID, or `None` if the subclass has
+    /// no such protocol.
+    ///
+    /// This is equivalent to [`Protocol::from_cid_scid_pid`] with the subclass's class and
+    /// subclass IDs, and is similarly cheap (`O(1)`).
+    pub fn get_protocol(&self, id: u8) -> Option<&'static Protocol> {
+        Protocol::from_cid_scid_pid(self.class_id, self.id, id)
+    }
+
+    /// Returns `true` if the subclass has a [`Protocol`] with the given ID.
+    ///
+    /// This is `O(n)` in the subclass's protocols.
+    pub fn has_protocol(&self, id: u8) -> bool {
+        self.protocols.iter().any(|p| p.id == id)
+    }
+
+    /// Returns an iterator over the subclass's protocols' IDs.
+    pub fn protocol_ids(&self) -> impl ExactSizeIterator<Item = u8> {
+        self.protocols.iter().map(|p| p.id)
+    }
+
+    /// Returns an iterator over the subclass's protocols' names.
+    pub fn protocol_names(&self) -> impl ExactSizeIterator<Item = &'static str> {
+        self.protocols.iter().map(|p| p.name)
+    }
+
+    /// Returns the number of protocols belonging to the subclass.
+    ///
+    /// Unlike counting via the iterator, this is cheap (`O(1)`).
+    pub fn protocol_count(&self) -> usize {
+        self.protocols.len()
+    }
+
+    /// Returns `true` if the subclass has no protocols.
+    pub fn is_empty(&self) -> bool {
+        self.protocols.is_empty()
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl fmt::Display for SubClass {
+    /// Formats the subclass as `class / subclass (cid/scid)`, e.g.
+    /// `Human Interface Device / Boot Interface Subclass (0x03/0x01)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} / {} ({:#04x}/{:#04x})",
+            self.class().name,
+            self.name,
+            self.class_id,
+            self.id
+        )
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl fmt::LowerHex for SubClass {
+    /// Formats the subclass's IDs as `cid/scid` as zero-padded lowercase hex, e.g. `0e/01`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02x}/{:02x}", self.class_id, self.id)
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl fmt::UpperHex for SubClass {
+    /// Formats the subclass's IDs as `cid/scid` as zero-padded uppercase hex, e.g. `0E/01`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02X}/{:02X}", self.class_id, self.id)
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl PartialOrd for SubClass {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl Ord for SubClass {
+    /// Orders subclasses by class ID, then by subclass ID.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_cid_scid().cmp(&other.as_cid_scid())
+    }
+}
+
+/// Represents a subclass protocol in the USB database.
+///
+/// Protocols are part of the USB class code triplet (base class, subclass,
+/// protocol), contained within a [`SubClass`].
+#[cfg(not(feature = "vendors-only"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Protocol {
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    class_id: u8,
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    subclass_id: u8,
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    id: u8,
+    name: &'static str,
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl Protocol {
+    /// Returns the [`Protocol`] corresponding to the given class, subclass, and protocol IDs,
+    /// or `None` if no such protocol exists in the DB.
+    ///
+    /// Looking up a protocol by class, subclass, and protocol IDs is cheap (`O(1)`).
+    ///
+    /// ```
+    /// use usb_ids::Protocol;
+    /// let protocol = Protocol::from_cid_scid_pid(0x02, 0x02, 0x05).unwrap();
+    /// assert_eq!(protocol.name(), "AT-commands (3G)");
+    /// ```
+    pub fn from_cid_scid_pid(class_id: u8, subclass_id: u8, id: u8) -> Option<&'static Self> {
+        USB_PROTOCOLS.get(&((class_id as u32) << 16 | (subclass_id as u32) << 8 | id as u32))
+    }
+
+    /// Returns the [`Protocol`] with exactly the given name under the given class and
+    /// subclass, or `None` if the subclass doesn't exist or has no such protocol.
+    ///
+    /// This is `O(n)` in the subclass's protocols.
+    ///
+    /// ```
+    /// use usb_ids::Protocol;
+    /// let protocol = Protocol::from_name_in_subclass(0x02, 0x02, "AT-commands (3G)").unwrap();
+    /// assert_eq!(protocol.id(), 0x05);
+    /// ```
+    pub fn from_name_in_subclass(
+        class_id: u8,
+        subclass_id: u8,
+        name: &str,
+    ) -> Option<&'static Self> {
+        SubClass::from_cid_scid(class_id, subclass_id)?.protocol_by_name(name)
+    }
+
+    /// Like [`Protocol::from_cid_scid_pid`], but reports which of the class, subclass,
+    /// or protocol is missing from the DB.
+    ///
+    /// ```
+    /// use usb_ids::{Protocol, UsbIdError};
+    /// assert!(Protocol::try_from_cid_scid_pid(0x02, 0x02, 0x05).is_ok());
+    /// assert_eq!(
+    ///     Protocol::try_from_cid_scid_pid(0x02, 0x02, 0xaa),
+    ///     Err(UsbIdError::ProtocolNotFound { class_id: 0x02, subclass_id: 0x02, protocol_id: 0xaa })
+    /// );
+    /// ```
+    pub fn try_from_cid_scid_pid(
+        class_id: u8,
+        subclass_id: u8,
+        id: u8,
+    ) -> Result<&'static Self, UsbIdError> {
+        match Protocol::from_cid_scid_pid(class_id, subclass_id, id) {
+            Some(protocol) => Ok(protocol),
+            None => {
+                SubClass::try_from_cid_scid(class_id, subclass_id)?;
+                Err(UsbIdError::ProtocolNotFound {
+                    class_id,
+                    subclass_id,
+                    protocol_id: id,
+                })
+            }
+        }
+    }
+
+    /// Returns the [`SubClass`] that this protocol belongs to.
+    ///
+    /// Looking up a subclass by protocol is cheap (`O(1)`).
+    ///
+    /// ```
+    /// use usb_ids::Protocol;
+    /// let protocol = Protocol::from_cid_scid_pid(0x02, 0x02, 0x05).unwrap();
+    /// assert_eq!(protocol.subclass().name(), "Abstract (modem)");
+    /// ```
+    pub fn subclass(&self) -> &'static SubClass {
+        SubClass::from_cid_scid(self.class_id, self.subclass_id).unwrap()
+    }
+
+    /// Returns the [`Class`] that this protocol belongs to.
+    ///
+    /// Looking up a class by protocol is cheap (`O(1)`).
+    ///
+    /// ```
+    /// use usb_ids::Protocol;
+    /// let protocol = Protocol::from_cid_scid_pid(0x02, 0x02, 0x05).unwrap();
+    /// assert_eq!(protocol.class().id(), 0x02);
+    /// ```
+    pub fn class(&self) -> &'static Class {
+        USB_CLASSES.get(&self.class_id).unwrap()
+    }
+
+    /// Returns the protocol's full path, as `class / subclass / protocol`.
+    ///
+    /// ```
+    /// use usb_ids::Protocol;
+    /// let protocol = Protocol::from_cid_scid_pid(0x02, 0x02, 0x05).unwrap();
+    /// assert_eq!(
+    ///     protocol.full_path(),
+    ///     "Communications / Abstract (modem) / AT-commands (3G)"
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn full_path(&self) -> alloc::string::String {
+        alloc::format!(
+            "{} / {} / {}",
+            self.class().name,
+            self.subclass().name,
+            self.name
+        )
+    }
+
+    /// Returns a tuple of (class id, subclass id, protocol id) for this protocol.
+    ///
+    /// This is convenient for interactions with other USB libraries, and is the inverse of
+    /// [`Protocol::from_cid_scid_pid`].
+    ///
+    /// ```
+    /// use usb_ids::Protocol;
+    /// let protocol = Protocol::from_cid_scid_pid(0x02, 0x02, 0x05).unwrap();
+    /// assert_eq!(protocol.as_cid_scid_pid(), (0x02, 0x02, 0x05));
+    /// ```
+    pub fn as_cid_scid_pid(&self) -> (u8, u8, u8) {
+        (self.class_id, self.subclass_id, self.id)
+    }
+
+    /// Returns the protocol's ID.
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    /// Returns the protocol's name.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl fmt::Display for Protocol {
+    /// Formats the protocol as `class / subclass / protocol (cid/scid/pid)`, e.g.
+    /// `Human Interface Device / Boot Interface Subclass / Keyboard (0x03/0x01/0x01)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} / {} / {} ({:#04x}/{:#04x}/{:#04x})",
+            self.class().name,
+            self.subclass().name,
+            self.name,
+            self.class_id,
+            self.subclass_id,
+            self.id
+        )
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl fmt::LowerHex for Protocol {
+    /// Formats the protocol's IDs as `cid/scid/pid` as zero-padded lowercase hex, e.g. `e0/01/01`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02x}/{:02x}/{:02x}",
+            self.class_id, self.subclass_id, self.id
+        )
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl fmt::UpperHex for Protocol {
+    /// Formats the protocol's IDs as `cid/scid/pid` as zero-padded uppercase hex, e.g. `E0/01/01`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02X}/{:02X}/{:02X}",
+            self.class_id, self.subclass_id, self.id
+        )
+    }
+}
+
+/// These are tags for UsbId type aliases to make them unique and allow a
+/// [`FromId`] for each alias. The values are arbitrary but must be unique.
+///
+/// [`std::marker::PhantomData`] would be nicer but was unable to figure out a
+/// generic way to add the _tag: PhantomData in the ToToken trait
+/// implementation within build.rs
+#[cfg(not(feature = "no-audio"))]
+const AT_TAG: u8 = 1;
+#[cfg(not(feature = "no-hid"))]
+const HID_TAG: u8 = 2;
+#[cfg(not(feature = "no-hid"))]
+const HID_TYPE_TAG: u8 = 3;
+#[cfg(not(feature = "no-hid"))]
+const BIAS_TAG: u8 = 5;
+#[cfg(not(feature = "no-hid"))]
+const PHY_TAG: u8 = 6;
+#[cfg(not(feature = "no-hid"))]
+const HCC_TAG: u8 = 8;
+#[cfg(not(feature = "no-video"))]
+const VT_TAG: u8 = 9;
+
+/// Represents an audio terminal type in the USB database.
+///
+/// ```
+/// use usb_ids::{AudioTerminal, FromId};
+/// let audio_terminal = AudioTerminal::from_id(0x0201).unwrap();
+/// assert_eq!(audio_terminal.name(), "Microphone");
+/// ```
+#[cfg(not(feature = "no-audio"))]
+pub type AudioTerminal = UsbId<AT_TAG, u16>;
+
+#[cfg(not(feature = "no-audio"))]
+impl AudioTerminal {
+    /// Returns the [`AudioTerminal`] with exactly the given name, or `None` if no such audio terminal
+    /// exists in the DB.
+    ///
+    /// Looking up a audio terminal by name is expensive (`O(n)`) unless the `name-index`
+    /// feature is enabled; prefer [`FromId::from_id`] when the ID is known.
+    ///
+    /// ```
+    /// use usb_ids::{AudioTerminal, FromId};
+    /// let audio_terminal = AudioTerminal::from_name("Microphone").unwrap();
+    /// assert_eq!(AudioTerminal::from_id(audio_terminal.id()), Some(audio_terminal));
+    /// ```
+    pub fn from_name(name: &str) -> Option<&'static AudioTerminal> {
+        static INDEX: NameIndex<AudioTerminal> = NameIndex::new();
+        INDEX.find(AudioTerminals::iter(), name)
+    }
+
+    /// Returns an iterator over every [`AudioTerminal`] whose name contains `query`.
+    ///
+    /// The search is case-sensitive and expensive (`O(n)`).
+    pub fn search_by_name(query: &str) -> impl Iterator<Item = &'static AudioTerminal> + '_ {
+        AudioTerminals::iter().filter(move |x| x.name().contains(query))
+    }
+
+    /// Returns `true` if this is an input terminal type (`0x02xx`), like a microphone.
+    ///
+    /// The ranges are from the USB Audio "Terminal Types" specification, which the
+    /// database follows. Note that this means input terminals start at `0x0200`, not
+    /// `0x0100`: the `0x01xx` range holds the USB terminal types (like USB streaming),
+    /// which are neither input nor output, and external terminals are `0x06xx`, not
+    /// `0x07xx` (which holds the embedded function terminal types).
+    ///
+    /// ```
+    /// use usb_ids::{AudioTerminal, FromId};
+    /// assert!(AudioTerminal::from_id(0x0201).unwrap().is_input());
+    /// assert!(!AudioTerminal::from_id(0x0301).unwrap().is_input());
+    ///
+    /// let streaming = AudioTerminal::from_id(0x0101).unwrap();
+    /// assert!(!streaming.is_input() && !streaming.is_output());
+    /// ```
+    pub fn is_input(&self) -> bool {
+        self.id >> 8 == 0x02
+    }
+
+    /// Returns `true` if this is an output terminal type (`0x03xx`), like a speaker.
+    pub fn is_output(&self) -> bool {
+        self.id >> 8 == 0x03
+    }
+
+    /// Returns `true` if this is a bidirectional terminal type (`0x04xx`), like a headset.
+    pub fn is_bidirectional(&self) -> bool {
+        self.id >> 8 == 0x04
+    }
+
+    /// Returns `true` if this is an external terminal type (`0x06xx`), like an analog
+    /// connector.
+    pub fn is_external(&self) -> bool {
+        self.id >> 8 == 0x06
+    }
+}
+
+/// Represents a HID descriptor type in the USB database.
+///
+/// ```
+/// use usb_ids::{Hid, FromId};
+/// let hid = Hid::from_id(0x22).unwrap();
+/// assert_eq!(hid.name(), "Report");
+/// ```
+#[cfg(not(feature = "no-hid"))]
+pub type Hid = UsbId<HID_TAG, u8>;
+
+/// Represents a HID descriptor item type in the USB database.
+///
+/// ```
+/// use usb_ids::{HidItemType, FromId};
+/// let hid_item_type = HidItemType::from_id(0xb4).unwrap();
+/// assert_eq!(hid_item_type.name(), "Pop");
+/// ```
+#[cfg(not(feature = "no-hid"))]
+pub type HidItemType = UsbId<HID_TYPE_TAG, u8>;
+
+/// Represents a HID usage page in the USB database.
+///
+/// Every HID usage page has a usage page ID, a pretty name, and a list of
+/// associated [`HidUsage`]s.
+///
+/// ```
+/// use usb_ids::{HidUsagePage, FromId};
+/// let hid_usage_page = HidUsagePage::from_id(0x01).unwrap();
+/// assert_eq!(hid_usage_page.name(), "Generic Desktop Controls");
+///
+/// for usage in hid_usage_page.usages() {
+///   println!("usage: {}", usage.name());
+/// }
+/// ```
+#[cfg(not(feature = "no-hid"))]
+pub type HidUsagePage = UsbIdWithChildren<u8, HidUsage>;
+
+#[cfg(not(feature = "no-hid"))]
+impl HidUsagePage {
+    /// Returns the [`HidUsagePage`] with exactly the given name, or `None` if no such HID usage page
+    /// exists in the DB.
+    ///
+    /// Looking up a HID usage page by name is expensive (`O(n)`) unless the `name-index`
+    /// feature is enabled; prefer [`FromId::from_id`] when the ID is known.
+    ///
+    /// ```
+    /// use usb_ids::{HidUsagePage, FromId};
+    /// let hid_usage_page = HidUsagePage::from_name("Generic Desktop Controls").unwrap();
+    /// assert_eq!(HidUsagePage::from_id(hid_usage_page.id()), Some(hid_usage_page));
+    /// ```
+    pub fn from_name(name: &str) -> Option<&'static HidUsagePage> {
+        static INDEX: NameIndex<HidUsagePage> = NameIndex::new();
+        INDEX.find(HidUsagePages::iter(), name)
+    }
+
+    /// Returns an iterator over every [`HidUsagePage`] whose name contains `query`.
+    ///
+    /// The search is case-sensitive and expensive (`O(n)`).
+    pub fn search_by_name(query: &str) -> impl Iterator<Item = &'static HidUsagePage> + '_ {
+        HidUsagePages::iter().filter(move |x| x.name().contains(query))
+    }
+
+    /// Returns an iterator over the page's [`HidUsage`]s.
+    pub fn usages(&self) -> HidUsageIter {
+        HidUsageIter::from_slice(self.children)
+    }
+
+    /// Returns the page's [`HidUsage`] with exactly the given name, or `None` if the page
+    /// has no such usage.
+    ///
+    /// This is expensive (`O(n)` in the page's usages).
+    pub fn usage_by_name(&self, name: &str) -> Option<&'static HidUsage> {
+        self.children.iter().find(|u| u.name == name)
+    }
+
+    /// Returns the page's first [`HidUsage`] matching the given predicate, or `None` if no
+    /// usage matches.
+    ///
+    /// This is `O(n)` in the page's usages; prefer [`HidUsage::from_pageid_uid`] when the
+    /// usage's ID is known.
+    pub fn find_usage(&self, mut pred: impl FnMut(&HidUsage) -> bool) -> Option<&'static HidUsage> {
+        self.children.iter().find(|u| pred(u))
+    }
+
+    /// Returns the page's [`HidUsage`] with the given ID, or `None` if the page has no such
+    /// usage.
+    ///
+    /// This is `O(n)` in the page's usages.
+    pub fn get_usage(&self, id: u16) -> Option<&'static HidUsage> {
+        self.children.iter().find(|u| u.id == id)
+    }
+
+    /// Returns `true` if the page has a [`HidUsage`] with the given ID.
+    ///
+    /// This is `O(n)` in the page's usages.
+    pub fn has_usage(&self, id: u16) -> bool {
+        self.children.iter().any(|u| u.id == id)
+    }
+
+    /// Returns an iterator over the page's usages' IDs.
+    pub fn usage_ids(&self) -> impl ExactSizeIterator<Item = u16> {
+        self.children.iter().map(|u| u.id)
+    }
+
+    /// Returns an iterator over the page's usages' names.
+    pub fn usage_names(&self) -> impl ExactSizeIterator<Item = &'static str> {
+        self.children.iter().map(|u| u.name)
+    }
+
+    /// Returns the number of usages belonging to the page.
+    ///
+    /// Unlike counting via the iterator, this is cheap (`O(1)`).
+    pub fn usage_count(&self) -> usize {
+        self.children.len()
+    }
+}
+
+/// Represents a HID usage type in the USB database.
+///
+/// ```
+/// use usb_ids::{HidUsage, HidUsagePage, FromId};
+///
+/// let hid_usage_page = HidUsagePage::from_id(0x01).unwrap();
+/// for usage in hid_usage_page.usages() {
+///    println!("usage: {}", usage.name());
+/// }
+/// ```
+#[cfg(not(feature = "no-hid"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct HidUsage {
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    page_id: u8,
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    id: u16,
+    name: &'static str,
+}
+
+#[cfg(not(feature = "no-hid"))]
+impl HidUsage {
+    /// Returns the [`HidUsage`] corresponding to the given usage page and usage ID,
+    /// or `None` if no such usage exists in the DB.
+    ///
+    /// ```
+    /// use usb_ids::HidUsage;
+    /// let hid_usage = HidUsage::from_pageid_uid(0x01, 0x002).unwrap();
+    /// assert_eq!(hid_usage.name(), "Mouse");
+    /// ```
+    pub fn from_pageid_uid(page_id: u8, id: u16) -> Option<&'static Self> {
+        let page = HidUsagePage::from_id(page_id)?;
+
+        page.children().find(|u| u.id() == id)
+    }
+
+    /// Returns the [`HidUsage`] with exactly the given name under the given usage page, or
+    /// `None` if the page doesn't exist or has no such usage.
+    ///
+    /// This is `O(n)` in the page's usages.
+    ///
+    /// ```
+    /// use usb_ids::HidUsage;
+    /// let hid_usage = HidUsage::from_name_in_page(0x01, "Mouse").unwrap();
+    /// assert_eq!(hid_usage.id(), 0x002);
+    /// ```
+    pub fn from_name_in_page(page_id: u8, name: &str) -> Option<&'static Self> {
+        HidUsagePage::from_id(page_id)?.usage_by_name(name)
+    }
+
+    /// Returns the [`HidUsagePage`] that this usage belongs to.
+    ///
+    /// Looking up a page by usage is cheap (`O(1)`).
+    ///
+    /// ```
+    /// use usb_ids::HidUsage;
+    /// let hid_usage = HidUsage::from_pageid_uid(0x01, 0x002).unwrap();
+    /// assert_eq!(hid_usage.page().name(), "Generic Desktop Controls");
+    /// ```
+    pub fn page(&self) -> &'static HidUsagePage {
+        USB_HUTS.get(&self.page_id).unwrap()
+    }
+
+    /// Returns the usage's full path, as `page / usage`.
+    ///
+    /// ```
+    /// use usb_ids::HidUsage;
+    /// let hid_usage = HidUsage::from_pageid_uid(0x01, 0x002).unwrap();
+    /// assert_eq!(hid_usage.full_path(), "Generic Desktop Controls / Mouse");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn full_path(&self) -> alloc::string::String {
+        alloc::format!("{} / {}", self.page().name, self.name)
+    }
+
+    /// Returns a tuple of (usage page id, usage id) for this usage.
+    ///
+    /// This is convenient for interactions with other USB libraries, and is the inverse of
+    /// [`HidUsage::from_pageid_uid`].
+    ///
+    /// ```
+    /// use usb_ids::HidUsage;
+    /// let hid_usage = HidUsage::from_pageid_uid(0x01, 0x002).unwrap();
+    /// assert_eq!(hid_usage.as_pageid_uid(), (0x01, 0x002));
+    /// ```
+    pub fn as_pageid_uid(&self) -> (u8, u16) {
+        (self.page_id, self.id)
+    }
+
+    /// Returns the usage's page and usage IDs combined into a single value, as
+    /// `page << 16 | usage`.
+    ///
+    /// This uniquely identifies the usage across all pages, and is the "extended usage"
+    /// form used in HID report descriptors.
+    ///
+    /// ```
+    /// use usb_ids::HidUsage;
+    /// let hid_usage = HidUsage::from_pageid_uid(0x01, 0x002).unwrap();
+    /// assert_eq!(hid_usage.qualified_id(), 0x0001_0002);
+    /// ```
+    pub fn qualified_id(&self) -> u32 {
+        (self.page_id as u32) << 16 | self.id as u32
+    }
+
+    /// Returns the usage's ID.
+    pub fn id(&self) -> u16 {
+        self.id
+    }
+
+    /// Returns the usage's name.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+#[cfg(not(feature = "no-hid"))]
+impl fmt::Display for HidUsage {
+    /// Formats the usage as `page / usage (page id/usage id)`, e.g.
+    /// `Generic Desktop Controls / Mouse (0x01/0x0002)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} / {} ({:#04x}/{:#06x})",
+            self.page().name,
+            self.name,
+            self.page_id,
+            self.id
+        )
+    }
+}
+
+/// Represents physical descriptor bias type in the USB database.
+///
+/// ```
+/// use usb_ids::{Bias, FromId};
+/// let bias = Bias::from_id(0x02).unwrap();
+/// assert_eq!(bias.name(), "Left Hand");
+/// ```
+#[cfg(not(feature = "no-hid"))]
+pub type Bias = UsbId<BIAS_TAG, u8>;
+
+/// Represents physical descriptor item type in the USB database.
+///
+/// ```
+/// use usb_ids::{Phy, FromId};
+/// let phy = Phy::from_id(0x25).unwrap();
+/// assert_eq!(phy.name(), "Fifth Toe");
+/// ```
+#[cfg(not(feature = "no-hid"))]
+pub type Phy = UsbId<PHY_TAG, u8>;
+
+/// Represents a language type in the USB database.
+///
+/// Languages have a language ID, a pretty name, and a list of associated
+/// [`Dialect`]s.
+///
+/// ```
+/// use usb_ids::{Language, FromId};
+/// let language = Language::from_id(0x000c).unwrap();
+/// assert_eq!(language.name(), "French");
+///
+/// for dialect in language.dialects() {
+///   println!("dialect: {}", dialect.name());
+/// }
+/// ```
+#[cfg(not(feature = "no-languages"))]
+pub type Language = UsbIdWithChildren<u16, Dialect>;
+
+#[cfg(not(feature = "no-languages"))]
+impl Language {
+    /// Returns the [`Language`] with exactly the given name, or `None` if no such language
+    /// exists in the DB.
+    ///
+    /// Looking up a language by name is expensive (`O(n)`) unless the `name-index`
+    /// feature is enabled; prefer [`FromId::from_id`] when the ID is known.
+    ///
+    /// ```
+    /// use usb_ids::{Language, FromId};
+    /// let language = Language::from_name("French").unwrap();
+    /// assert_eq!(Language::from_id(language.id()), Some(language));
+    /// ```
+    pub fn from_name(name: &str) -> Option<&'static Language> {
+        static INDEX: NameIndex<Language> = NameIndex::new();
+        INDEX.find(Languages::iter(), name)
+    }
+
+    /// Returns an iterator over every [`Language`] whose name contains `query`.
+    ///
+    /// The search is case-sensitive and expensive (`O(n)`).
+    pub fn search_by_name(query: &str) -> impl Iterator<Item = &'static Language> + '_ {
+        Languages::iter().filter(move |x| x.name().contains(query))
+    }
+
+    /// Returns an iterator over the language's [`Dialect`]s.
+    pub fn dialects(&self) -> DialectIter {
+        DialectIter::from_slice(self.children)
+    }
+
+    /// Returns the language's [`Dialect`] with exactly the given name, or `None` if the
+    /// language has no such dialect.
+    ///
+    /// This is expensive (`O(n)` in the language's dialects).
+    pub fn dialect_by_name(&self, name: &str) -> Option<&'static Dialect> {
+        self.children.iter().find(|d| d.name == name)
+    }
+
+    /// Returns the language's [`Dialect`] with the given ID, or `None` if the language has
+    /// no such dialect.
+    ///
+    /// This is `O(n)` in the language's dialects.
+    pub fn get_dialect(&self, id: u8) -> Option<&'static Dialect> {
+        self.children.iter().find(|d| d.id == id)
+    }
+
+    /// Returns `true` if the language has a [`Dialect`] with the given ID.
+    ///
+    /// This is `O(n)` in the language's dialects.
+    pub fn has_dialect(&self, id: u8) -> bool {
+        self.children.iter().any(|d| d.id == id)
+    }
+
+    /// Returns an iterator over the language's dialects' IDs.
+    pub fn dialect_ids(&self) -> impl ExactSizeIterator<Item = u8> {
+        self.children.iter().map(|d| d.id)
+    }
+
+    /// Returns an iterator over the language's dialects' names.
+    pub fn dialect_names(&self) -> impl ExactSizeIterator<Item = &'static str> {
+        self.children.iter().map(|d| d.name)
+    }
+
+    /// Returns the number of dialects belonging to the language.
+    ///
+    /// Unlike counting via the iterator, this is cheap (`O(1)`).
+    pub fn dialect_count(&self) -> usize {
+        self.children.len()
+    }
+}
+
+/// Represents a language dialect in the USB database.
+///
+/// ```
+/// use usb_ids::{Dialect, Language, FromId};
+/// let lang = Language::from_id(0x0007).unwrap();
+///
+/// println!("language: {}", lang.name());
+/// for dialect in lang.dialects() {
+///    println!("\tdialect: {}", dialect.name());
+/// }
+/// ```
+#[cfg(not(feature = "no-languages"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Dialect {
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    language_id: u16,
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    id: u8,
+    name: &'static str,
+}
+
+#[cfg(not(feature = "no-languages"))]
+impl Dialect {
+    /// Returns the [`Dialect`] corresponding to the given language and dialect IDs,
+    /// or `None` if no such dialect exists in the DB.
+    ///
+    /// ```
+    /// use usb_ids::Dialect;
+    /// let dialect = Dialect::from_lid_did(0x0007, 0x02).unwrap();
+    /// assert_eq!(dialect.name(), "Swiss");
+    /// ```
+    pub fn from_lid_did(language_id: u16, id: u8) -> Option<&'static Self> {
+        let language = Language::from_id(language_id)?;
+
+        language.children().find(|d| d.id() == id)
+    }
+
+    /// Returns the [`Dialect`] with exactly the given name under the given language, or
+    /// `None` if the language doesn't exist or has no such dialect.
+    ///
+    /// This is `O(n)` in the language's dialects.
+    ///
+    /// ```
+    /// use usb_ids::Dialect;
+    /// let dialect = Dialect::from_name_in_language(0x0007, "Swiss").unwrap();
+    /// assert_eq!(dialect.id(), 0x02);
+    /// ```
+    pub fn from_name_in_language(language_id: u16, name: &str) -> Option<&'static Self> {
+        Language::from_id(language_id)?.dialect_by_name(name)
+    }
+
+    /// Returns the [`Language`] that this dialect belongs to.
+    ///
+    /// Looking up a language by dialect is cheap (`O(1)`).
+    ///
+    /// ```
+    /// use usb_ids::Dialect;
+    /// let dialect = Dialect::from_lid_did(0x0007, 0x02).unwrap();
+    /// assert_eq!(dialect.language().name(), "German");
+    /// ```
+    pub fn language(&self) -> &'static Language {
+        USB_LANGS.get(&self.language_id).unwrap()
+    }
+
+    /// Returns a tuple of (language id, dialect id) for this dialect.
+    ///
+    /// This is convenient for interactions with other USB libraries, and is the inverse of
+    /// [`Dialect::from_lid_did`].
+    ///
+    /// ```
+    /// use usb_ids::Dialect;
+    /// let dialect = Dialect::from_lid_did(0x0007, 0x02).unwrap();
+    /// assert_eq!(dialect.as_lid_did(), (0x0007, 0x02));
+    /// ```
+    pub fn as_lid_did(&self) -> (u16, u8) {
+        (self.language_id, self.id)
+    }
+
+    /// Returns the dialect's ID.
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    /// Returns the dialect's name.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+#[cfg(not(feature = "no-languages"))]
+impl fmt::Display for Dialect {
+    /// Formats the dialect as `language / dialect (lid/did)`, e.g. `German / Swiss (0x0007/0x02)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} / {} ({:#06x}/{:#04x})",
+            self.language().name,
+            self.name,
+            self.language_id,
+            self.id
+        )
+    }
+}
+
+/// Represents a HID descriptor country code in the USB database.
+///
+/// ```
+/// use usb_ids::{HidCountryCode, FromId};
+/// let hid_country_code = HidCountryCode::from_id(0x29).unwrap();
+/// assert_eq!(hid_country_code.name(), "Switzerland");
+/// ```
+#[cfg(not(feature = "no-hid"))]
+pub type HidCountryCode = UsbId<HCC_TAG, u8>;
+
+#[cfg(not(feature = "no-hid"))]
+impl HidCountryCode {
+    /// Returns the [`HidCountryCode`] with exactly the given name, or `None` if no such HID country code
+    /// exists in the DB.
+    ///
+    /// Looking up a HID country code by name is expensive (`O(n)`) unless the `name-index`
+    /// feature is enabled; prefer [`FromId::from_id`] when the ID is known.
+    ///
+    /// ```
+    /// use usb_ids::{HidCountryCode, FromId};
+    /// let hid_country_code = HidCountryCode::from_name("Switzerland").unwrap();
+    /// assert_eq!(HidCountryCode::from_id(hid_country_code.id()), Some(hid_country_code));
+    /// ```
+    pub fn from_name(name: &str) -> Option<&'static HidCountryCode> {
+        static INDEX: NameIndex<HidCountryCode> = NameIndex::new();
+        INDEX.find(HidCountryCodes::iter(), name)
+    }
+
+    /// Returns an iterator over every [`HidCountryCode`] whose name contains `query`.
+    ///
+    /// The search is case-sensitive and expensive (`O(n)`).
+    pub fn search_by_name(query: &str) -> impl Iterator<Item = &'static HidCountryCode> + '_ {
+        HidCountryCodes::iter().filter(move |x| x.name().contains(query))
+    }
+}
+
+/// Represents a video class terminal type in the USB database.
+///
+/// ```
+/// use usb_ids::{VideoTerminal, FromId};
+/// let video_terminal = VideoTerminal::from_id(0x0101).unwrap();
+/// assert_eq!(video_terminal.name(), "USB Streaming");
+/// ```
+#[cfg(not(feature = "no-video"))]
+pub type VideoTerminal = UsbId<VT_TAG, u16>;
+
+#[cfg(not(feature = "no-video"))]
+impl VideoTerminal {
+    /// Returns the [`VideoTerminal`] with exactly the given name, or `None` if no such video terminal
+    /// exists in the DB.
+    ///
+    /// Looking up a video terminal by name is expensive (`O(n)`) unless the `name-index`
+    /// feature is enabled; prefer [`FromId::from_id`] when the ID is known.
+    ///
+    /// ```
+    /// use usb_ids::{VideoTerminal, FromId};
+    /// let video_terminal = VideoTerminal::from_name("USB Streaming").unwrap();
+    /// assert_eq!(VideoTerminal::from_id(video_terminal.id()), Some(video_terminal));
+    /// ```
+    pub fn from_name(name: &str) -> Option<&'static VideoTerminal> {
+        static INDEX: NameIndex<VideoTerminal> = NameIndex::new();
+        INDEX.find(VideoTerminals::iter(), name)
+    }
+
+    /// Returns an iterator over every [`VideoTerminal`] whose name contains `query`.
+    ///
+    /// The search is case-sensitive and expensive (`O(n)`).
+    pub fn search_by_name(query: &str) -> impl Iterator<Item = &'static VideoTerminal> + '_ {
+        VideoTerminals::iter().filter(move |x| x.name().contains(query))
+    }
+
+    /// Returns `true` if this is an input terminal type (`0x02xx`), like a camera sensor.
+    ///
+    /// The ranges are from the USB Video Class specification, which the database follows.
+    /// Note that this means input terminals start at `0x0200`, not `0x0100`: the `0x01xx`
+    /// range holds the USB terminal types (like USB streaming), which are neither input
+    /// nor output.
+    ///
+    /// ```
+    /// use usb_ids::{FromId, VideoTerminal};
+    /// assert!(VideoTerminal::from_id(0x0201).unwrap().is_input());
+    /// assert!(!VideoTerminal::from_id(0x0301).unwrap().is_input());
+    ///
+    /// let streaming = VideoTerminal::from_id(0x0101).unwrap();
+    /// assert!(!streaming.is_input() && !streaming.is_output());
+    /// ```
+    pub fn is_input(&self) -> bool {
+        self.id >> 8 == 0x02
+    }
+
+    /// Returns `true` if this is an output terminal type (`0x03xx`), like a display.
+    pub fn is_output(&self) -> bool {
+        self.id >> 8 == 0x03
+    }
+}
+
+/// A convenience trait for retrieving a top-level entity (like a [`Vendor`]) from the USB
+/// database by its unique ID.
+///
+/// ```
+/// use usb_ids::{FromId, Vendor};
+/// let vendor = Vendor::from_id(0x1d6b).unwrap();
+/// assert_eq!(vendor.name(), "Linux Foundation");
+/// ```
+pub trait FromId<T> {
+    /// Returns the entity corresponding to `id`, or `None` if none exists.
+    fn from_id(id: T) -> Option<&'static Self>;
+}
+
+impl FromId<u16> for Vendor {
+    fn from_id(id: u16) -> Option<&'static Self> {
+        USB_IDS.get(&id)
+    }
+}
+
+impl FromId<VendorId> for Vendor {
+    fn from_id(id: VendorId) -> Option<&'static Self> {
+        Vendor::from_id(id.0)
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl FromId<u8> for Class {
+    fn from_id(id: u8) -> Option<&'static Self> {
+        USB_CLASSES.get(&id)
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl FromId<ClassId> for Class {
+    fn from_id(id: ClassId) -> Option<&'static Self> {
+        Class::from_id(id.0)
+    }
+}
+
+#[cfg(not(feature = "no-audio"))]
+impl FromId<u16> for AudioTerminal {
+    fn from_id(id: u16) -> Option<&'static Self> {
+        USB_AUDIO_TERMINALS.get(&id)
+    }
+}
+
+#[cfg(not(feature = "no-hid"))]
+impl FromId<u8> for Hid {
+    fn from_id(id: u8) -> Option<&'static Self> {
+        USB_HID_IDS.get(&id)
+    }
+}
+
+#[cfg(not(feature = "no-hid"))]
+impl FromId<u8> for HidItemType {
+    fn from_id(id: u8) -> Option<&'static Self> {
+        USB_HID_R_TYPES.get(&id)
+    }
+}
+
+#[cfg(not(feature = "no-hid"))]
+impl FromId<u8> for HidUsagePage {
+    fn from_id(id: u8) -> Option<&'static Self> {
+        USB_HUTS.get(&id)
+    }
+}
+
+#[cfg(not(feature = "no-hid"))]
+impl FromId<u8> for Bias {
+    fn from_id(id: u8) -> Option<&'static Self> {
+        USB_BIASES.get(&id)
+    }
+}
+
+#[cfg(not(feature = "no-hid"))]
+impl FromId<u8> for Phy {
+    fn from_id(id: u8) -> Option<&'static Self> {
+        USB_PHYS.get(&id)
+    }
+}
+
+#[cfg(not(feature = "no-languages"))]
+impl FromId<u16> for Language {
+    fn from_id(id: u16) -> Option<&'static Self> {
+        USB_LANGS.get(&id)
+    }
+}
+
+#[cfg(not(feature = "no-hid"))]
+impl FromId<u8> for HidCountryCode {
+    fn from_id(id: u8) -> Option<&'static Self> {
+        USB_HID_CCS.get(&id)
+    }
+}
+
+#[cfg(not(feature = "no-video"))]
+impl FromId<u16> for VideoTerminal {
+    fn from_id(id: u16) -> Option<&'static Self> {
+        USB_VIDEO_TERMINALS.get(&id)
+    }
+}
+
+impl AsRef<str> for Vendor {
+    /// Returns the vendor's name, like [`Vendor::name`].
+    fn as_ref(&self) -> &str {
+        self.name
+    }
+}
+
+impl AsRef<str> for Device {
+    /// Returns the device's name, like [`Device::name`].
+    fn as_ref(&self) -> &str {
+        self.name
+    }
+}
+
+impl AsRef<str> for Interface {
+    /// Returns the interface's name, like [`Interface::name`].
+    fn as_ref(&self) -> &str {
+        self.name
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl AsRef<str> for Class {
+    /// Returns the class's name, like [`Class::name`].
+    fn as_ref(&self) -> &str {
+        self.name
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl AsRef<str> for SubClass {
+    /// Returns the subclass's name, like [`SubClass::name`].
+    fn as_ref(&self) -> &str {
+        self.name
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl AsRef<str> for Protocol {
+    /// Returns the protocol's name, like [`Protocol::name`].
+    fn as_ref(&self) -> &str {
+        self.name
+    }
+}
+
+#[cfg(not(feature = "no-hid"))]
+impl AsRef<str> for HidUsage {
+    /// Returns the usage's name, like [`HidUsage::name`].
+    fn as_ref(&self) -> &str {
+        self.name
+    }
+}
+
+#[cfg(not(feature = "no-languages"))]
+impl AsRef<str> for Dialect {
+    /// Returns the dialect's name, like [`Dialect::name`].
+    fn as_ref(&self) -> &str {
+        self.name
+    }
+}
+
+impl AsRef<[Device]> for Vendor {
+    /// Returns the vendor's devices, in database order.
+    fn as_ref(&self) -> &[Device] {
+        self.devices
+    }
+}
+
+impl AsRef<[Interface]> for Device {
+    /// Returns the device's interfaces, in database order.
+    fn as_ref(&self) -> &[Interface] {
+        self.interfaces
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl AsRef<[SubClass]> for Class {
+    /// Returns the class's subclasses, in database order.
+    fn as_ref(&self) -> &[SubClass] {
+        self.sub_classes
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl AsRef<[Protocol]> for SubClass {
+    /// Returns the subclass's protocols, in database order.
+    fn as_ref(&self) -> &[Protocol] {
+        self.protocols
+    }
+}
+
+impl Index<u16> for Vendor {
+    type Output = Device;
+
+    /// Returns the vendor's [`Device`] with the given product ID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vendor has no such device; use [`Vendor::has_device`] or
+    /// [`Device::from_vid_pid`] when that's possible.
+    ///
+    /// ```
+    /// use usb_ids::{FromId, Vendor};
+    /// let vendor = Vendor::from_id(0x1d6b).unwrap();
+    /// assert_eq!(vendor[0x0003].name(), "3.0 root hub");
+    /// ```
+    fn index(&self, pid: u16) -> &Device {
+        self.devices
+            .iter()
+            .find(|d| d.id == pid)
+            .unwrap_or_else(|| panic!("No device {:#06x} under vendor {:#06x}", pid, self.id))
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl Index<u8> for Class {
+    type Output = SubClass;
+
+    /// Returns the class's [`SubClass`] with the given ID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the class has no such subclass; use [`Class::has_subclass`] or
+    /// [`SubClass::from_cid_scid`] when that's possible.
+    fn index(&self, id: u8) -> &SubClass {
+        self.sub_classes
+            .iter()
+            .find(|s| s.id == id)
+            .unwrap_or_else(|| panic!("No subclass {:#04x} under class {:#04x}", id, self.id))
+    }
+}
+
+/// An error returned by the `try_*` lookups, describing which part of the lookup failed.
+///
+/// More variants may be added as more lookups gain `try_*` forms, so matches on this
+/// need a wildcard arm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UsbIdError {
+    /// No vendor with the given ID exists in the DB.
+    VendorNotFound(u16),
+    /// The vendor exists, but has no device with the given product ID.
+    DeviceNotFound {
+        /// The vendor ID.
+        vid: u16,
+        /// The device/"product" ID.
+        pid: u16,
+    },
+    /// No class with the given ID exists in the DB.
+    ClassNotFound(u8),
+    /// The class exists, but has no subclass with the given ID.
+    SubClassNotFound {
+        /// The class ID.
+        class_id: u8,
+        /// The subclass ID.
+        subclass_id: u8,
+    },
+    /// The subclass exists, but has no protocol with the given ID.
+    ProtocolNotFound {
+        /// The class ID.
+        class_id: u8,
+        /// The subclass ID.
+        subclass_id: u8,
+        /// The protocol ID.
+        protocol_id: u8,
+    },
+    /// No audio terminal type with the given ID exists in the DB.
+    AudioTerminalNotFound(u16),
+    /// No HID descriptor type with the given ID exists in the DB.
+    HidNotFound(u8),
+    /// No HID item type with the given ID exists in the DB.
+    HidItemTypeNotFound(u8),
+    /// No bias with the given ID exists in the DB.
+    BiasNotFound(u8),
+    /// No physical descriptor item with the given ID exists in the DB.
+    PhyNotFound(u8),
+    /// No HID usage page with the given ID exists in the DB.
+    HidUsagePageNotFound(u8),
+    /// No language with the given ID exists in the DB.
+    LanguageNotFound(u16),
+    /// No HID country code with the given ID exists in the DB.
+    HidCountryCodeNotFound(u8),
+    /// No video terminal type with the given ID exists in the DB.
+    VideoTerminalNotFound(u16),
+}
+
+impl fmt::Display for UsbIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UsbIdError::VendorNotFound(vid) => {
+                write!(f, "no vendor {:#06x} in the USB database", vid)
+            }
+            UsbIdError::DeviceNotFound { vid, pid } => {
+                write!(f, "vendor {:#06x} has no device {:#06x}", vid, pid)
+            }
+            UsbIdError::ClassNotFound(class_id) => {
+                write!(f, "no class {:#04x} in the USB database", class_id)
+            }
+            UsbIdError::SubClassNotFound {
+                class_id,
+                subclass_id,
+            } => write!(
+                f,
+                "class {:#04x} has no subclass {:#04x}",
+                class_id, subclass_id
+            ),
+            UsbIdError::ProtocolNotFound {
+                class_id,
+                subclass_id,
+                protocol_id,
+            } => write!(
+                f,
+                "subclass {:#04x}/{:#04x} has no protocol {:#04x}",
+                class_id, subclass_id, protocol_id
+            ),
+            UsbIdError::AudioTerminalNotFound(id) => {
+                write!(f, "no audio terminal type {:#06x} in the USB database", id)
+            }
+            UsbIdError::HidNotFound(id) => {
+                write!(f, "no HID descriptor type {:#04x} in the USB database", id)
+            }
+            UsbIdError::HidItemTypeNotFound(id) => {
+                write!(f, "no HID item type {:#04x} in the USB database", id)
+            }
+            UsbIdError::BiasNotFound(id) => {
+                write!(f, "no bias {:#04x} in the USB database", id)
+            }
+            UsbIdError::PhyNotFound(id) => {
+                write!(
+                    f,
+                    "no physical descriptor item {:#04x} in the USB database",
+                    id
+                )
+            }
+            UsbIdError::HidUsagePageNotFound(id) => {
+                write!(f, "no HID usage page {:#04x} in the USB database", id)
+            }
+            UsbIdError::LanguageNotFound(id) => {
+                write!(f, "no language {:#06x} in the USB database", id)
+            }
+            UsbIdError::HidCountryCodeNotFound(id) => {
+                write!(f, "no HID country code {:#04x} in the USB database", id)
+            }
+            UsbIdError::VideoTerminalNotFound(id) => {
+                write!(f, "no video terminal type {:#06x} in the USB database", id)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UsbIdError {}
+
+impl TryFrom<u16> for &'static Vendor {
+    type Error = UsbIdError;
+
+    /// Looks up a vendor by ID, like [`FromId::from_id`].
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use usb_ids::Vendor;
+    /// let vendor = <&Vendor>::try_from(0x1d6b).unwrap();
+    /// assert_eq!(vendor.name(), "Linux Foundation");
+    /// ```
+    fn try_from(id: u16) -> Result<Self, Self::Error> {
+        Vendor::from_id(id).ok_or(UsbIdError::VendorNotFound(id))
+    }
+}
+
+impl TryFrom<(u16, u16)> for &'static Device {
+    type Error = UsbIdError;
+
+    /// Looks up a device by `(vendor id, device id)`, like [`Device::from_vid_pid`].
+    fn try_from((vid, pid): (u16, u16)) -> Result<Self, Self::Error> {
+        Device::try_from_vid_pid(vid, pid)
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl TryFrom<u8> for &'static Class {
+    type Error = UsbIdError;
+
+    /// Looks up a class by ID, like [`FromId::from_id`].
+    fn try_from(id: u8) -> Result<Self, Self::Error> {
+        Class::from_id(id).ok_or(UsbIdError::ClassNotFound(id))
+    }
+}
+
+#[cfg(not(feature = "no-audio"))]
+impl TryFrom<u16> for &'static AudioTerminal {
+    type Error = UsbIdError;
+
+    /// Looks up a audio terminal type by ID, like [`FromId::from_id`].
+    fn try_from(id: u16) -> Result<Self, Self::Error> {
+        AudioTerminal::from_id(id).ok_or(UsbIdError::AudioTerminalNotFound(id))
+    }
+}
+
+#[cfg(not(feature = "no-hid"))]
+impl TryFrom<u8> for &'static Hid {
+    type Error = UsbIdError;
+
+    /// Looks up a HID descriptor type by ID, like [`FromId::from_id`].
+    fn try_from(id: u8) -> Result<Self, Self::Error> {
+        Hid::from_id(id).ok_or(UsbIdError::HidNotFound(id))
+    }
+}
+
+#[cfg(not(feature = "no-hid"))]
+impl TryFrom<u8> for &'static HidItemType {
+    type Error = UsbIdError;
+
+    /// Looks up a HID item type by ID, like [`FromId::from_id`].
+    fn try_from(id: u8) -> Result<Self, Self::Error> {
+        HidItemType::from_id(id).ok_or(UsbIdError::HidItemTypeNotFound(id))
+    }
+}
+
+#[cfg(not(feature = "no-hid"))]
+impl TryFrom<u8> for &'static Bias {
+    type Error = UsbIdError;
+
+    /// Looks up a bias by ID, like [`FromId::from_id`].
+    fn try_from(id: u8) -> Result<Self, Self::Error> {
+        Bias::from_id(id).ok_or(UsbIdError::BiasNotFound(id))
+    }
+}
+
+#[cfg(not(feature = "no-hid"))]
+impl TryFrom<u8> for &'static Phy {
+    type Error = UsbIdError;
+
+    /// Looks up a physical descriptor item by ID, like [`FromId::from_id`].
+    fn try_from(id: u8) -> Result<Self, Self::Error> {
+        Phy::from_id(id).ok_or(UsbIdError::PhyNotFound(id))
+    }
+}
+
+#[cfg(not(feature = "no-hid"))]
+impl TryFrom<u8> for &'static HidUsagePage {
+    type Error = UsbIdError;
+
+    /// Looks up a HID usage page by ID, like [`FromId::from_id`].
+    fn try_from(id: u8) -> Result<Self, Self::Error> {
+        HidUsagePage::from_id(id).ok_or(UsbIdError::HidUsagePageNotFound(id))
+    }
+}
+
+#[cfg(not(feature = "no-languages"))]
+impl TryFrom<u16> for &'static Language {
+    type Error = UsbIdError;
+
+    /// Looks up a language by ID, like [`FromId::from_id`].
+    fn try_from(id: u16) -> Result<Self, Self::Error> {
+        Language::from_id(id).ok_or(UsbIdError::LanguageNotFound(id))
+    }
+}
+
+#[cfg(not(feature = "no-hid"))]
+impl TryFrom<u8> for &'static HidCountryCode {
+    type Error = UsbIdError;
+
+    /// Looks up a HID country code by ID, like [`FromId::from_id`].
+    fn try_from(id: u8) -> Result<Self, Self::Error> {
+        HidCountryCode::from_id(id).ok_or(UsbIdError::HidCountryCodeNotFound(id))
+    }
+}
+
+#[cfg(not(feature = "no-video"))]
+impl TryFrom<u16> for &'static VideoTerminal {
+    type Error = UsbIdError;
+
+    /// Looks up a video terminal type by ID, like [`FromId::from_id`].
+    fn try_from(id: u16) -> Result<Self, Self::Error> {
+        VideoTerminal::from_id(id).ok_or(UsbIdError::VideoTerminalNotFound(id))
+    }
+}
+
+impl From<&Vendor> for u16 {
+    /// Returns the vendor's ID, like [`Vendor::id`].
+    ///
+    /// ```
+    /// use usb_ids::{FromId, Vendor};
+    /// let vid: u16 = Vendor::from_id(0x1d6b).unwrap().into();
+    /// assert_eq!(vid, 0x1d6b);
+    /// ```
+    fn from(vendor: &Vendor) -> Self {
+        vendor.id
+    }
+}
+
+impl From<&Device> for (u16, u16) {
+    /// Returns the device's `(vendor ID, product ID)` pair, like [`Device::as_vid_pid`].
+    ///
+    /// ```
+    /// use usb_ids::Device;
+    /// let (vid, pid) = Device::from_vid_pid(0x1d6b, 0x0003).unwrap().into();
+    /// assert_eq!((vid, pid), (0x1d6b, 0x0003));
+    /// ```
+    fn from(device: &Device) -> Self {
+        device.as_vid_pid()
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl From<&Class> for u8 {
+    /// Returns the class's ID, like [`Class::id`].
+    fn from(class: &Class) -> Self {
+        class.id
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl From<&SubClass> for (u8, u8) {
+    /// Returns the subclass's `(class ID, subclass ID)` pair, like [`SubClass::as_cid_scid`].
+    fn from(subclass: &SubClass) -> Self {
+        subclass.as_cid_scid()
+    }
+}
+
+/// An error returned when parsing one of the ID newtypes (like [`VidPid`]) from a string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseIdError {
+    expected: &'static str,
+}
+
+impl fmt::Display for ParseIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid USB ID, expected {}", self.expected)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseIdError {}
+
+/// Parses a bare (not `0x`-prefixed) hex ID of at most `digits` digits.
+fn parse_hex_id<T>(
+    s: &str,
+    digits: usize,
+    from_str_radix: fn(&str, u32) -> Result<T, ParseIntError>,
+) -> Option<T> {
+    if s.is_empty() || s.len() > digits || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    from_str_radix(s, 16).ok()
+}
+
+/// A USB vendor ID.
+///
+/// Distinct from [`ProductId`], so that vendor and device IDs can't be accidentally swapped.
+///
+/// ```
+/// use usb_ids::{Device, FromId, ProductId, Vendor, VendorId};
+/// let vendor = Vendor::from_id(VendorId(0x1d6b)).unwrap();
+/// assert_eq!(vendor.name(), "Linux Foundation");
+///
+/// let device = Device::from_ids(VendorId(0x1d6b), ProductId(0x0003)).unwrap();
+/// assert_eq!(device.name(), "3.0 root hub");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VendorId(
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))] pub u16,
+);
+
+/// A USB device/"product" ID.
+///
+/// Distinct from [`VendorId`], so that vendor and device IDs can't be accidentally swapped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ProductId(
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))] pub u16,
+);
+
+impl From<u16> for VendorId {
+    fn from(id: u16) -> Self {
+        VendorId(id)
+    }
+}
+
+impl From<VendorId> for u16 {
+    fn from(id: VendorId) -> Self {
+        id.0
+    }
+}
+
+impl From<u16> for ProductId {
+    fn from(id: u16) -> Self {
+        ProductId(id)
+    }
+}
+
+impl From<ProductId> for u16 {
+    fn from(id: ProductId) -> Self {
+        id.0
+    }
+}
+
+/// A USB class ID.
+///
+/// ```
+/// use usb_ids::{Class, ClassId, FromId};
+/// let class = Class::from_id(ClassId(0x09)).unwrap();
+/// assert_eq!(class.name(), "Hub");
+/// ```
+#[cfg(not(feature = "vendors-only"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ClassId(
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))] pub u8,
+);
+
+#[cfg(not(feature = "vendors-only"))]
+impl From<u8> for ClassId {
+    fn from(id: u8) -> Self {
+        ClassId(id)
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl From<ClassId> for u8 {
+    fn from(id: ClassId) -> Self {
+        id.0
+    }
+}
+
+/// Deserializes a numeric ID from an integer, a decimal string, or a `0x`-prefixed hex string.
+#[cfg(feature = "serde")]
+struct IdVisitor<T> {
+    from_str_radix: fn(&str, u32) -> Result<T, ParseIntError>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: TryFrom<u64>> serde::de::Visitor<'de> for IdVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an integer ID, or a decimal or `0x`-prefixed hex string")
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<T, E> {
+        T::try_from(v).map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<T, E> {
+        match u64::try_from(v) {
+            Ok(v) => self.visit_u64(v),
+            Err(_) => Err(E::invalid_value(serde::de::Unexpected::Signed(v), &self)),
+        }
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<T, E> {
+        let digits = 2 * core::mem::size_of::<T>();
+        let id = match v.strip_prefix("0x").or_else(|| v.strip_prefix("0X")) {
+            Some(hex) => parse_hex_id(hex, digits, self.from_str_radix),
+            None if !v.is_empty() && v.bytes().all(|b| b.is_ascii_digit()) => {
+                (self.from_str_radix)(v, 10).ok()
+            }
+            None => None,
+        };
+
+        id.ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for VendorId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let visitor = IdVisitor {
+            from_str_radix: u16::from_str_radix,
+        };
+        deserializer.deserialize_any(visitor).map(VendorId)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ProductId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let visitor = IdVisitor {
+            from_str_radix: u16::from_str_radix,
+        };
+        deserializer.deserialize_any(visitor).map(ProductId)
+    }
+}
+
+#[cfg(all(feature = "serde", not(feature = "vendors-only")))]
+impl<'de> serde::Deserialize<'de> for ClassId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let visitor = IdVisitor {
+            from_str_radix: u8::from_str_radix,
+        };
+        deserializer.deserialize_any(visitor).map(ClassId)
+    }
+}
+
+/// A `(vendor id, device id)` pair, as commonly written in `vid:pid` notation (e.g. `1d6b:0003`).
+///
+/// ```
+/// use usb_ids::VidPid;
+/// let vid_pid: VidPid = "1d6b:0003".parse().unwrap();
+/// assert_eq!(vid_pid, (0x1d6b, 0x0003));
+/// assert_eq!(vid_pid.to_string(), "1d6b:0003");
+/// assert_eq!(vid_pid.lookup().unwrap().name(), "3.0 root hub");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VidPid {
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    vid: u16,
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    pid: u16,
+}
+
+impl VidPid {
+    /// Creates a new `VidPid` from the given vendor and device IDs.
+    pub fn new(vid: u16, pid: u16) -> Self {
+        VidPid { vid, pid }
+    }
+
+    /// Returns the vendor ID.
+    pub fn vid(&self) -> u16 {
+        self.vid
+    }
+
+    /// Returns the device/"product" ID.
+    pub fn pid(&self) -> u16 {
+        self.pid
+    }
+
+    /// Returns the [`Device`] with this vendor and device ID, if one exists in the DB.
+    pub fn lookup(&self) -> Option<&'static Device> {
+        Device::from_vid_pid(self.vid, self.pid)
+    }
+}
+
+impl fmt::Display for VidPid {
+    /// Formats the pair as `vid:pid`, e.g. `1d6b:0003`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04x}:{:04x}", self.vid, self.pid)
+    }
+}
+
+impl FromStr for VidPid {
+    type Err = ParseIdError;
+
+    /// Parses a pair in `vid:pid` notation, with each ID as up to four hex digits.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = ParseIdError {
+            expected: "`vid:pid` in hex",
+        };
+        let (vid, pid) = s.split_once(':').ok_or(err)?;
+        let vid = parse_hex_id(vid, 4, u16::from_str_radix).ok_or(err)?;
+        let pid = parse_hex_id(pid, 4, u16::from_str_radix).ok_or(err)?;
+
+        Ok(VidPid { vid, pid })
+    }
+}
+
+impl From<(u16, u16)> for VidPid {
+    fn from((vid, pid): (u16, u16)) -> Self {
+        VidPid { vid, pid }
+    }
+}
+
+impl From<VidPid> for (u16, u16) {
+    fn from(vid_pid: VidPid) -> Self {
+        (vid_pid.vid, vid_pid.pid)
+    }
+}
+
+impl PartialEq<(u16, u16)> for VidPid {
+    fn eq(&self, other: &(u16, u16)) -> bool {
+        (self.vid, self.pid) == *other
+    }
+}
+
+/// A USB class code triplet (base class, subclass, protocol), as found in the
+/// `bDeviceClass`, `bDeviceSubClass`, and `bDeviceProtocol` descriptor fields.
+///
+/// ```
+/// use usb_ids::ClassTriple;
+/// let triple: ClassTriple = "02/02/05".parse().unwrap();
+/// assert_eq!(triple.to_string(), "02/02/05");
+///
+/// let (class, subclass, protocol) = triple.lookup().unwrap();
+/// assert_eq!(class.name(), "Communications");
+/// assert_eq!(subclass.name(), "Abstract (modem)");
+/// assert_eq!(protocol.name(), "AT-commands (3G)");
+/// ```
+#[cfg(not(feature = "vendors-only"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ClassTriple {
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    class_id: u8,
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    subclass_id: u8,
+    #[cfg_attr(feature = "serde_hex_ids", serde(serialize_with = "serialize_hex_id"))]
+    protocol_id: u8,
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl ClassTriple {
+    /// Creates a new `ClassTriple` from the given class, subclass, and protocol IDs.
+    pub fn new(class_id: u8, subclass_id: u8, protocol_id: u8) -> Self {
+        ClassTriple {
+            class_id,
+            subclass_id,
+            protocol_id,
+        }
+    }
+
+    /// Returns the class ID.
+    pub fn class_id(&self) -> u8 {
+        self.class_id
+    }
+
+    /// Returns the subclass ID.
+    pub fn subclass_id(&self) -> u8 {
+        self.subclass_id
+    }
+
+    /// Returns the protocol ID.
+    pub fn protocol_id(&self) -> u8 {
+        self.protocol_id
+    }
+
+    /// Returns the [`Class`], [`SubClass`], and [`Protocol`] for this triplet, if the
+    /// protocol exists in the DB.
+    pub fn lookup(&self) -> Option<(&'static Class, &'static SubClass, &'static Protocol)> {
+        lookup_class_triple(self.class_id, self.subclass_id, self.protocol_id)
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl fmt::Display for ClassTriple {
+    /// Formats the triplet as `class/subclass/protocol`, e.g. `02/02/05`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02x}/{:02x}/{:02x}",
+            self.class_id, self.subclass_id, self.protocol_id
+        )
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl FromStr for ClassTriple {
+    type Err = ParseIdError;
+
+    /// Parses a triplet in `class/subclass/protocol` notation, with each ID as up to two
+    /// hex digits.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = ParseIdError {
+            expected: "`class/subclass/protocol` in hex",
+        };
+        let mut ids = s
+            .split('/')
+            .map(|id| parse_hex_id(id, 2, u8::from_str_radix));
+        let triple = match (ids.next(), ids.next(), ids.next(), ids.next()) {
+            (Some(Some(class_id)), Some(Some(subclass_id)), Some(Some(protocol_id)), None) => {
+                ClassTriple::new(class_id, subclass_id, protocol_id)
+            }
+            _ => return Err(err),
+        };
+
+        Ok(triple)
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl From<(u8, u8, u8)> for ClassTriple {
+    fn from((class_id, subclass_id, protocol_id): (u8, u8, u8)) -> Self {
+        ClassTriple::new(class_id, subclass_id, protocol_id)
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl From<ClassTriple> for (u8, u8, u8) {
+    fn from(triple: ClassTriple) -> Self {
+        (triple.class_id, triple.subclass_id, triple.protocol_id)
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+impl PartialEq<(u8, u8, u8)> for ClassTriple {
+    fn eq(&self, other: &(u8, u8, u8)) -> bool {
+        (self.class_id, self.subclass_id, self.protocol_id) == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_id() {
+        let vendor = Vendor::from_id(0x1d6b).unwrap();
+
+        assert_eq!(vendor.name(), "Linux Foundation");
+        assert_eq!(vendor.id(), 0x1d6b);
+    }
+
+    #[test]
+    fn test_vendor_from_name() {
+        let vendor = Vendor::from_name("Linux Foundation").unwrap();
+        assert_eq!(vendor.id(), 0x1d6b);
+
+        assert_eq!(Vendor::from_name("linux foundation"), None);
+        assert_eq!(Vendor::from_name("Linux"), None);
+        assert_eq!(
+            Vendor::from_name_ignore_case("LINUX FOUNDATION"),
+            Some(vendor)
+        );
+    }
+
+    #[test]
+    fn test_search_by_name() {
+        let vendors: Vec<_> = Vendor::search_by_name("Foundation").collect();
+        assert!(vendors.contains(&Vendor::from_id(0x1d6b).unwrap()));
+        assert!(vendors.iter().all(|v| v.name().contains("Foundation")));
+        assert_eq!(Vendor::search_by_name("foundation").count(), 0);
+
+        let devices: Vec<_> = Device::search_by_name("root hub").collect();
+        assert!(devices.contains(&Device::from_vid_pid(0x1d6b, 0x0003).unwrap()));
+        assert!(devices.iter().all(|d| d.name().contains("root hub")));
+    }
+
+    #[cfg(not(any(
+        feature = "no-audio",
+        feature = "no-video",
+        feature = "no-languages",
+        feature = "no-hid",
+        feature = "vendors-only"
+    )))]
+    #[test]
+    fn test_other_from_name() {
+        assert_eq!(Class::from_name("Hub"), Class::from_id(0x09));
+        assert_eq!(Class::from_name("hub"), None);
+        assert_eq!(
+            AudioTerminal::from_name("Microphone"),
+            AudioTerminal::from_id(0x0201)
+        );
+        assert_eq!(
+            VideoTerminal::from_name("USB Streaming"),
+            VideoTerminal::from_id(0x0101)
+        );
+        assert_eq!(Language::from_name("French"), Language::from_id(0x000c));
+        assert_eq!(
+            HidUsagePage::from_name("Generic Desktop Controls"),
+            HidUsagePage::from_id(0x01)
+        );
+        assert_eq!(
+            HidCountryCode::from_name("Switzerland"),
+            HidCountryCode::from_id(0x29)
+        );
+
+        assert!(Class::search_by_name("Device").all(|c| c.name().contains("Device")));
+        assert!(Language::search_by_name("French").any(|l| l.id() == 0x000c));
+        assert!(HidUsagePage::search_by_name("Desktop").any(|p| p.id() == 0x01));
+        assert_eq!(VideoTerminal::search_by_name("no such terminal").count(), 0);
+    }
+
+    #[cfg(not(any(feature = "no-languages", feature = "no-hid", feature = "vendors-only")))]
+    #[test]
+    fn test_child_counts() {
+        for vendor in Vendors::iter() {
+            assert_eq!(vendor.device_count(), vendor.devices().count());
+            assert_eq!(vendor.is_empty(), vendor.devices().next().is_none());
+        }
+        for class in Classes::iter() {
+            assert_eq!(class.subclass_count(), class.sub_classes().count());
+            assert_eq!(class.is_empty(), class.subclass_count() == 0);
+        }
+        for subclass in SubClasses::iter() {
+            assert_eq!(subclass.protocol_count(), subclass.protocols().count());
+            assert_eq!(subclass.is_empty(), subclass.protocol_count() == 0);
+        }
+        for page in HidUsagePages::iter() {
+            assert_eq!(page.usage_count(), page.usages().count());
+            assert_eq!(page.is_empty(), page.usage_count() == 0);
+        }
+        for language in Languages::iter() {
+            assert_eq!(language.dialect_count(), language.dialects().count());
+            assert_eq!(language.is_empty(), language.dialect_count() == 0);
+        }
+        assert!(!Vendor::from_id(0x1d6b).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_total_counts() {
+        assert_eq!(total_vendor_count(), Vendors::iter().count());
+        assert_eq!(total_vendor_count(), USB_IDS.len());
+        assert_eq!(total_device_count(), Devices::iter().count());
+        assert_eq!(total_device_count(), USB_DEVICES.len());
+
+        // Sanity check that the DB was loaded in full.
+        assert!(total_vendor_count() > 3000);
+        assert!(total_device_count() > 20000);
+    }
+
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    fn test_total_class_count() {
+        assert_eq!(total_class_count(), Classes::iter().count());
+        assert_eq!(SUBCLASS_COUNT, SubClasses::iter().count());
+        assert_eq!(PROTOCOL_COUNT, Protocols::iter().count());
+    }
+
+    #[test]
+    fn test_interface_count() {
+        assert_eq!(INTERFACE_COUNT, all_interfaces().count());
+    }
+
+    #[cfg(not(any(
+        feature = "no-hid",
+        feature = "no-audio",
+        feature = "no-video",
+        feature = "no-languages"
+    )))]
+    #[test]
+    fn test_entry_counts() {
+        let counts = [
+            (VENDOR_COUNT, Vendors::iter().count()),
+            (DEVICE_COUNT, Devices::iter().count()),
+            (CLASS_COUNT, Classes::iter().count()),
+            (SUBCLASS_COUNT, SubClasses::iter().count()),
+            (PROTOCOL_COUNT, Protocols::iter().count()),
+            (AUDIO_TERMINAL_COUNT, AudioTerminals::iter().count()),
+            (HID_COUNT, Hids::iter().count()),
+            (HID_ITEM_TYPE_COUNT, HidItemTypes::iter().count()),
+            (BIAS_COUNT, Biases::iter().count()),
+            (PHY_COUNT, Phys::iter().count()),
+            (HID_USAGE_PAGE_COUNT, HidUsagePages::iter().count()),
+            (HID_USAGE_COUNT, all_hid_usages().count()),
+            (LANGUAGE_COUNT, Languages::iter().count()),
+            (DIALECT_COUNT, Dialects::iter().count()),
+            (HID_COUNTRY_CODE_COUNT, HidCountryCodes::iter().count()),
+            (VIDEO_TERMINAL_COUNT, VideoTerminals::iter().count()),
+        ];
+
+        for (constant, counted) in counts {
+            assert!(constant > 0);
+            assert_eq!(constant, counted);
+        }
+    }
+
+    #[test]
+    fn test_lookup_vendor_and_device() {
+        let (vendor, device) = lookup_vendor_and_device(0x1d6b, 0x0003).unwrap();
+        assert_eq!(vendor, Vendor::from_id(0x1d6b).unwrap());
+        assert_eq!(device, Device::from_vid_pid(0x1d6b, 0x0003).unwrap());
+        assert!(lookup_vendor_and_device(0x1d6b, 0x9999).is_none());
+        assert!(lookup_vendor_and_device(0x0000, 0x0000).is_none());
+    }
+
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    fn test_lookup_class_triple() {
+        let (class, subclass, protocol) = lookup_class_triple(0x02, 0x02, 0x01).unwrap();
+        assert_eq!(class, Class::from_id(0x02).unwrap());
+        assert_eq!(subclass, SubClass::from_cid_scid(0x02, 0x02).unwrap());
+        assert_eq!(
+            protocol,
+            Protocol::from_cid_scid_pid(0x02, 0x02, 0x01).unwrap()
+        );
+
+        // Short-circuits at whichever part is missing
+        assert!(lookup_class_triple(0x02, 0x02, 0xaa).is_none());
+        assert!(lookup_class_triple(0x02, 0xaa, 0x01).is_none());
+        assert!(lookup_class_triple(0xab, 0x02, 0x01).is_none());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_iter_sorted() {
+        let vendors: Vec<_> = Vendors::iter_sorted_by_name().collect();
+        assert_eq!(vendors.len(), total_vendor_count());
+        assert!(vendors
+            .windows(2)
+            .all(|w| (w[0].name(), w[0].id()) <= (w[1].name(), w[1].id())));
+
+        let vendors: Vec<_> = Vendors::iter_sorted_by_id().collect();
+        assert_eq!(vendors.len(), total_vendor_count());
+        assert!(vendors.windows(2).all(|w| w[0].id() < w[1].id()));
+    }
+
+    #[test]
+    fn test_device_by_name() {
+        let vendor = Vendor::from_id(0x1d6b).unwrap();
+        let device = Device::from_vid_pid(0x1d6b, 0x0003).unwrap();
+        assert_eq!(vendor.device_by_name("3.0 root hub"), Some(device));
+        assert_eq!(vendor.device_by_name("3.0 Root Hub"), None);
+        assert_eq!(vendor.device_by_name_ci("3.0 Root Hub"), Some(device));
+        assert_eq!(vendor.device_by_name_ci("3.0 root"), None);
+    }
+
+    #[cfg(all(feature = "alloc", not(feature = "no-hid")))]
+    #[test]
+    fn test_full_path() {
+        let device = Device::from_vid_pid(0x1d6b, 0x0002).unwrap();
+        assert_eq!(device.full_path(), "Linux Foundation / 2.0 root hub");
+
+        let protocol = Protocol::from_cid_scid_pid(0x03, 0x01, 0x01).unwrap();
+        assert_eq!(
+            protocol.full_path(),
+            "Human Interface Device / Boot Interface Subclass / Keyboard"
+        );
+        assert_eq!(
+            protocol.subclass().full_path(),
+            "Human Interface Device / Boot Interface Subclass"
+        );
+
+        let usage = HidUsage::from_pageid_uid(0x01, 0x002).unwrap();
+        assert_eq!(usage.full_path(), "Generic Desktop Controls / Mouse");
+    }
+
+    #[test]
+    fn test_interface_classes_unspecified() {
+        // The database doesn't record interfaces' classes, so they're all unspecified
+        for interface in all_interfaces() {
+            assert_eq!(
+                (
+                    interface.class_id(),
+                    interface.subclass_id(),
+                    interface.protocol_id()
+                ),
+                (0, 0, 0)
+            );
+            #[cfg(not(feature = "vendors-only"))]
+            {
+                assert!(interface.class().is_none());
+                assert!(interface.protocol().is_none());
+            }
+        }
+    }
+
+    #[test]
+    fn test_all_interfaces() {
+        let count: usize = Devices::iter().map(|d| d.interfaces().count()).sum();
+        assert_eq!(all_interfaces().count(), count);
+        assert!(all_interfaces().all(|i| i.device().interfaces().any(|j| j == i)));
+    }
+
+    #[cfg(not(feature = "no-hid"))]
+    #[test]
+    fn test_all_hid_usages() {
+        let count: usize = HidUsagePages::iter().map(|p| p.usage_count()).sum();
+        assert_eq!(all_hid_usages().count(), count);
+    }
+
+    #[test]
+    fn test_all_vendor_devices() {
+        assert_eq!(all_vendor_devices().count(), total_device_count());
+        assert!(all_vendor_devices().all(|(v, d)| d.vendor() == v));
+    }
+
+    #[cfg(not(any(feature = "no-hid", feature = "no-languages")))]
+    #[test]
+    fn test_all_other_pairs() {
+        assert_eq!(all_class_triples().count(), Protocols::iter().count());
+        assert!(all_class_triples().all(|(c, s, p)| p.subclass() == s && s.class() == c));
+
+        assert_eq!(
+            all_hid_page_usages().count(),
+            Database::all_hid_usages().count()
+        );
+        assert!(all_hid_page_usages().all(|(p, u)| u.page() == p));
+
+        assert_eq!(all_language_dialects().count(), Dialects::iter().count());
+        assert!(all_language_dialects().all(|(l, d)| d.language() == l));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_describe_vid_pid() {
+        assert_eq!(
+            describe_vid_pid(0x1d6b, 0x0002).unwrap(),
+            "Linux Foundation 2.0 root hub (0x1d6b:0x0002)"
+        );
+        assert_eq!(
+            describe_vid_pid(0x1d6b, 0x9999).unwrap(),
+            "Linux Foundation (0x1d6b)"
+        );
+        assert_eq!(describe_vid_pid(0x0000, 0x0000), None);
+    }
+
+    #[cfg(all(feature = "alloc", not(feature = "vendors-only")))]
+    #[test]
+    fn test_describe_class_triple() {
+        assert_eq!(
+            describe_class_triple(0x02, 0x02, 0x01).unwrap(),
+            "Communications / Abstract (modem) / AT-commands (v.25ter) (0x02/0x02/0x01)"
+        );
+        assert_eq!(
+            describe_class_triple(0x02, 0x02, 0xaa).unwrap(),
+            "Communications / Abstract (modem) (0x02/0x02)"
+        );
+        assert_eq!(
+            describe_class_triple(0x02, 0xaa, 0x01).unwrap(),
+            "Communications (0x02)"
+        );
+        assert_eq!(describe_class_triple(0xab, 0x02, 0x01), None);
+    }
+
+    #[test]
+    fn test_all_names() {
+        assert_eq!(all_vendor_names().count(), total_vendor_count());
+        assert!(all_vendor_names().eq(Vendors::iter().map(|v| v.name())));
+        assert_eq!(all_device_names().count(), total_device_count());
+        assert!(all_device_names().any(|names| names == ("Linux Foundation", "2.0 root hub")));
+    }
+
+    #[cfg(not(any(feature = "no-hid", feature = "no-languages")))]
+    #[test]
+    fn test_all_other_names() {
+        assert_eq!(all_class_names().count(), total_class_count());
+        assert!(all_class_names().any(|name| name == "Human Interface Device"));
+        assert!(all_language_names().any(|name| name == "English"));
+        assert!(all_hid_usage_page_names().any(|name| name == "Generic Desktop Controls"));
+    }
+
+    fn name_of(named: impl AsRef<str>) -> String {
+        named.as_ref().to_string()
+    }
+
+    #[test]
+    fn test_as_ref_str() {
+        let device = Device::from_vid_pid(0x1d6b, 0x0003).unwrap();
+        assert_eq!(name_of(device), "3.0 root hub");
+        assert_eq!(name_of(device.vendor()), "Linux Foundation");
+    }
+
+    #[cfg(not(any(
+        feature = "no-audio",
+        feature = "no-video",
+        feature = "no-languages",
+        feature = "no-hid"
+    )))]
+    #[test]
+    fn test_other_as_ref_str() {
+        assert_eq!(
+            name_of(Class::from_id(0x03).unwrap()),
+            "Human Interface Device"
+        );
+        assert_eq!(
+            name_of(AudioTerminal::from_id(0x0201).unwrap()),
+            "Microphone"
+        );
+        assert_eq!(name_of(Language::from_id(0x0009).unwrap()), "English");
+        assert_eq!(
+            name_of(HidUsage::from_pageid_uid(0x01, 0x002).unwrap()),
+            "Mouse"
+        );
+        assert_eq!(
+            name_of(VideoTerminal::from_id(0x0100).unwrap()),
+            VideoTerminal::from_id(0x0100).unwrap().name()
+        );
+    }
+
+    #[test]
+    fn test_as_ref_slice() {
+        let vendor = Vendor::from_id(0x1d6b).unwrap();
+        let devices: &[Device] = vendor.as_ref();
+        assert_eq!(devices.len(), vendor.device_count());
+        assert!(devices.iter().eq(vendor.devices()));
+    }
+
+    #[cfg(not(any(feature = "no-hid", feature = "no-languages")))]
+    #[test]
+    fn test_other_as_ref_slice() {
+        let class = Class::from_id(0x02).unwrap();
+        let subclasses: &[SubClass] = class.as_ref();
+        assert!(subclasses.iter().eq(class.sub_classes()));
+
+        let subclass = SubClass::from_cid_scid(0x02, 0x02).unwrap();
+        let protocols: &[Protocol] = subclass.as_ref();
+        assert!(protocols.iter().eq(subclass.protocols()));
+
+        let page = HidUsagePage::from_id(0x01).unwrap();
+        let usages: &[HidUsage] = page.as_ref();
+        assert!(usages.iter().eq(page.usages()));
+
+        let language = Language::from_id(0x0009).unwrap();
+        let dialects: &[Dialect] = language.as_ref();
+        assert!(dialects.iter().eq(language.dialects()));
+    }
+
+    #[test]
+    fn test_id_and_name_eq() {
+        let vendor = Vendor::from_id(0x1d6b).unwrap();
+        assert!(*vendor == 0x1d6b);
+        assert!(*vendor != 0x1d6c);
+        assert!(*vendor == "Linux Foundation");
+        assert!(*vendor != "linux foundation");
+        assert_ne!(*vendor, "");
+        assert!(Vendors::iter().filter(|v| **v == 0x1d6b).eq([vendor]));
+        assert!(Vendors::iter()
+            .filter(|v| **v == "Linux Foundation")
+            .eq([vendor]));
+
+        let device = Device::from_vid_pid(0x1d6b, 0x0003).unwrap();
+        assert!(*device == (0x1d6b, 0x0003));
+        assert!(*device != (0x1d6b, 0x0002));
+        assert!(*device != (0x0003, 0x1d6b));
+    }
+
+    #[test]
+    fn test_into_ids() {
+        let vendor = Vendor::from_id(0x1d6b).unwrap();
+        assert_eq!(u16::from(vendor), 0x1d6b);
+
+        let device = Device::from_vid_pid(0x1d6b, 0x0003).unwrap();
+        let (vid, pid): (u16, u16) = device.into();
+        assert_eq!((vid, pid), (0x1d6b, 0x0003));
+
+        // The conversions round-trip through the TryFrom lookups.
+        assert_eq!(<&Vendor>::try_from(u16::from(vendor)), Ok(vendor));
+        assert_eq!(<&Device>::try_from(<(u16, u16)>::from(device)), Ok(device));
+    }
+
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    fn test_class_into_ids() {
+        let class = Class::from_id(0x09).unwrap();
+        assert_eq!(u8::from(class), 0x09);
+        assert_eq!(<&Class>::try_from(u8::from(class)), Ok(class));
+
+        let subclass = SubClass::from_cid_scid(0x02, 0x03).unwrap();
+        let ids: (u8, u8) = subclass.into();
+        assert_eq!(ids, (0x02, 0x03));
+    }
+
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    fn test_class_name_eq() {
+        let class = Class::from_id(0x09).unwrap();
+        assert!(*class == "Hub");
+        assert!(*class != "hub");
+        assert!(*class != "Hub ");
+        assert_ne!(*class, "");
+        assert!(Classes::iter().filter(|c| **c == "Hub").eq([class]));
+    }
+
+    #[test]
+    fn test_iter_with_ids() {
+        use std::collections::{BTreeMap, HashMap};
+
+        let vendors: HashMap<_, _> = Vendors::iter_with_ids().collect();
+        assert_eq!(vendors.len(), Vendors::iter().len());
+        assert_eq!(vendors.get(&0x1d6b).copied(), Vendor::from_id(0x1d6b));
+        assert_eq!(vendors.get(&0xffff).copied(), Vendor::from_id(0xffff));
+
+        let device = Device::from_vid_pid(0x1d6b, 0x0003).unwrap();
+        let devices: HashMap<_, _> = Devices::iter_with_ids().collect();
+        assert_eq!(devices.get(&(0x1d6b, 0x0003)).copied(), Some(device));
+        assert_eq!(devices.get(&(0x1d6b, 0x9999)), None);
+
+        let devices: BTreeMap<_, _> = Devices::iter_with_ids().collect();
+        assert_eq!(devices.get(&(0x1d6b, 0x0003)).copied(), Some(device));
+        assert_eq!(devices.len(), Devices::iter().len());
+    }
+
+    #[test]
+    fn test_index() {
+        let vendor = Vendor::from_id(0x1d6b).unwrap();
+        assert_eq!(
+            &vendor[0x0003],
+            Device::from_vid_pid(0x1d6b, 0x0003).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "No device 0x9999 under vendor 0x1d6b")]
+    fn test_index_missing() {
+        let _ = &Vendor::from_id(0x1d6b).unwrap()[0x9999];
+    }
+
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    fn test_class_index() {
+        let class = Class::from_id(0x02).unwrap();
+        assert_eq!(&class[0x02], SubClass::from_cid_scid(0x02, 0x02).unwrap());
+    }
+
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    #[should_panic(expected = "No subclass 0xaa under class 0x02")]
+    fn test_class_index_missing() {
+        let _ = &Class::from_id(0x02).unwrap()[0xaa];
+    }
+
+    #[test]
+    fn test_has_device() {
+        let vendor = Vendor::from_id(0x1d6b).unwrap();
+        assert!(vendor.has_device(0x0003));
+        assert!(!vendor.has_device(0x9999));
+    }
+
+    #[test]
+    fn test_device_ids() {
+        let vendor = Vendor::from_id(0x1d6b).unwrap();
+        let ids = vendor.device_ids();
+        assert_eq!(ids.len(), vendor.device_count());
+        assert!(ids.eq(vendor.devices().map(|d| d.id())));
+
+        let names = vendor.device_names();
+        assert_eq!(names.len(), vendor.device_count());
+        assert!(names.eq(vendor.devices().map(|d| d.name())));
+    }
+
+    #[cfg(not(any(feature = "no-hid", feature = "no-languages")))]
+    #[test]
+    fn test_child_ids() {
+        let class = Class::from_id(0x02).unwrap();
+        assert_eq!(class.subclass_ids().len(), class.subclass_count());
+        assert!(class.subclass_ids().eq(class.sub_classes().map(|s| s.id())));
+
+        let subclass = SubClass::from_cid_scid(0x02, 0x02).unwrap();
+        assert_eq!(subclass.protocol_ids().len(), subclass.protocol_count());
+        assert!(subclass
+            .protocol_ids()
+            .eq(subclass.protocols().map(|p| p.id())));
+
+        let page = HidUsagePage::from_id(0x01).unwrap();
+        assert_eq!(page.usage_ids().len(), page.usage_count());
+        assert!(page.usage_ids().eq(page.usages().map(|u| u.id())));
+
+        let language = Language::from_id(0x0009).unwrap();
+        assert_eq!(language.dialect_ids().len(), language.dialect_count());
+        assert!(language
+            .dialect_ids()
+            .eq(language.dialects().map(|d| d.id())));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_search_all() {
+        let results = search_all("ROOT HUB");
+        assert!(!results.is_empty());
+        assert_eq!(results.vendors().len(), 0);
+        assert!(results
+            .devices()
+            .any(|d| d.as_vid_pid() == (0x1d6b, 0x0003)));
+        assert!(results
+            .devices()
+            .all(|d| d.name().to_lowercase().contains("root hub")));
+
+        assert!(search_all("Linux Foundation")
+            .vendors()
+            .any(|v| v.id() == 0x1d6b));
+
+        let results = search_all("no such thing, surely");
+        assert!(results.is_empty());
+        assert_eq!(results, SearchResults::default());
+    }
+
+    #[cfg(all(
+        feature = "alloc",
+        not(any(feature = "no-hid", feature = "no-languages"))
+    ))]
+    #[test]
+    fn test_search_all_tables() {
+        let results = search_all("keyboard");
+        assert!(results
+            .protocols()
+            .any(|p| p.as_cid_scid_pid() == (0x03, 0x01, 0x01)));
+        assert!(results
+            .hid_usages()
+            .any(|u| u.as_pageid_uid() == (0x01, 0x006)));
+
+        let results = search_all("swiss");
+        assert!(results.dialects().any(|d| d.as_lid_did() == (0x0007, 0x02)));
+
+        assert!(search_all("report").hids().any(|h| h.id() == 0x22));
+        assert!(search_all("end collection")
+            .hid_item_types()
+            .any(|t| t.id() == 0xc0));
+        assert!(search_all("not applicable").biases().any(|b| b.id() == 0));
+        assert!(search_all("eyeball").phys().any(|p| p.id() == 0x02));
+        assert!(search_all("belgian")
+            .hid_country_codes()
+            .any(|c| c.id() == 0x02));
+    }
+
+    #[test]
+    fn test_vendor_from_name_all() {
+        for vendor in Vendors::iter() {
+            let first = Vendors::iter().find(|v| v.name() == vendor.name());
+            assert_eq!(Vendor::from_name(vendor.name()), first);
+        }
+        assert!(Vendor::from_name("").is_none());
+    }
+
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    fn test_class_from_name_all() {
+        for class in Classes::iter() {
+            assert_eq!(Class::from_name(class.name()).unwrap().name(), class.name());
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_search_by_regex() {
+        let vendors: Vec<_> = Vendor::search_by_regex("^Linux Foundation$")
+            .unwrap()
+            .collect();
+        assert_eq!(vendors, [Vendor::from_id(0x1d6b).unwrap()]);
+
+        let devices: Vec<_> = Device::search_by_regex(r"^\d\.\d root hub$")
+            .unwrap()
+            .collect();
+        assert!(devices.contains(&Device::from_vid_pid(0x1d6b, 0x0003).unwrap()));
+        assert!(devices.iter().all(|d| d.name().ends_with(" root hub")));
+
+        assert!(Vendor::search_by_regex("(unclosed").is_err());
+        assert!(Device::search_by_regex("[z-a]").is_err());
+    }
+
+    #[cfg(all(feature = "regex", not(feature = "vendors-only")))]
+    #[test]
+    fn test_class_search_by_regex() {
+        let classes: Vec<_> = Class::search_by_regex("(?i)^hub$").unwrap().collect();
+        assert_eq!(classes, [Class::from_id(0x09).unwrap()]);
+        assert!(Class::search_by_regex("*").is_err());
+    }
+
+    #[test]
+    fn test_find_device() {
+        let vendor = Vendor::from_id(0x1d6b).unwrap();
+        assert_eq!(
+            vendor.find_device(|d| d.id() == 0x0003),
+            Device::from_vid_pid(0x1d6b, 0x0003)
+        );
+        assert!(vendor.find_device(|d| d.name().is_empty()).is_none());
+    }
+
+    #[cfg(not(feature = "no-hid"))]
+    #[test]
+    fn test_find_child() {
+        let class = Class::from_id(0x03).unwrap();
+        let subclass = class.find_subclass(|s| s.name().contains("Boot")).unwrap();
+        assert_eq!(subclass.id(), 0x01);
+        assert!(class.find_subclass(|s| s.id() == 0xfe).is_none());
+
+        let protocol = subclass.find_protocol(|p| p.name() == "Mouse").unwrap();
+        assert_eq!(protocol.id(), 0x02);
+
+        let page = HidUsagePage::from_id(0x01).unwrap();
+        assert_eq!(
+            page.find_usage(|u| u.name() == "Mouse"),
+            HidUsage::from_pageid_uid(0x01, 0x002)
+        );
+    }
+
+    #[cfg(not(any(feature = "no-hid", feature = "no-languages")))]
+    #[test]
+    fn test_child_names() {
+        let class = Class::from_id(0x02).unwrap();
+        assert_eq!(class.subclass_names().len(), class.subclass_count());
+        assert!(class.subclass_names().any(|n| n == "Telephone"));
+
+        let subclass = SubClass::from_cid_scid(0x02, 0x02).unwrap();
+        assert!(subclass
+            .protocol_names()
+            .eq(subclass.protocols().map(|p| p.name())));
+
+        let page = HidUsagePage::from_id(0x01).unwrap();
+        assert!(page.usage_names().eq(page.usages().map(|u| u.name())));
+
+        let language = Language::from_id(0x0009).unwrap();
+        assert_eq!(language.dialect_names().len(), language.dialect_count());
+        assert!(language.dialect_names().any(|n| n == "UK"));
+    }
+
+    #[cfg(not(any(feature = "no-hid", feature = "no-languages")))]
+    #[test]
+    fn test_has_child() {
+        let class = Class::from_id(0x02).unwrap();
+        assert!(class.has_subclass(0x02));
+        assert!(!class.has_subclass(0xaa));
+
+        let subclass = SubClass::from_cid_scid(0x02, 0x02).unwrap();
+        assert!(subclass.has_protocol(0x01));
+        assert!(!subclass.has_protocol(0xaa));
+
+        let page = HidUsagePage::from_id(0x01).unwrap();
+        assert!(page.has_usage(0x002));
+        assert!(!page.has_usage(0xfff));
+
+        let language = Language::from_id(0x0009).unwrap();
+        assert!(language.has_dialect(0x02));
+        assert!(!language.has_dialect(0xff));
+    }
+
+    #[cfg(not(any(feature = "no-hid", feature = "no-languages")))]
+    #[test]
+    fn test_child_by_name() {
+        let class = Class::from_id(0x03).unwrap();
+        let subclass = class.subclass_by_name("Boot Interface Subclass").unwrap();
+        assert_eq!(subclass, SubClass::from_cid_scid(0x03, 0x01).unwrap());
+        assert_eq!(subclass.protocol_by_name("Keyboard").unwrap().id(), 0x01);
+        assert!(class.subclass_by_name("Keyboard").is_none());
+
+        let page = HidUsagePage::from_id(0x01).unwrap();
+        assert_eq!(page.usage_by_name("Mouse").unwrap().id(), 0x002);
+        assert!(page.usage_by_name("mouse").is_none());
+
+        let language = Language::from_id(0x0009).unwrap();
+        assert_eq!(language.dialect_by_name("UK").unwrap().id(), 0x02);
+        assert!(language.dialect_by_name("Klingon").is_none());
+    }
+
+    #[cfg(not(any(feature = "no-hid", feature = "no-languages")))]
+    #[test]
+    fn test_from_name_in_parent() {
+        assert_eq!(
+            SubClass::from_name_in_class(0x02, "Telephone"),
+            SubClass::from_cid_scid(0x02, 0x03)
+        );
+        assert!(SubClass::from_name_in_class(0x02, "Keyboard").is_none());
+        assert!(SubClass::from_name_in_class(0x3c, "Telephone").is_none());
+
+        assert_eq!(
+            Protocol::from_name_in_subclass(0x03, 0x01, "Keyboard"),
+            Protocol::from_cid_scid_pid(0x03, 0x01, 0x01)
+        );
+        assert!(Protocol::from_name_in_subclass(0x02, 0x02, "Keyboard").is_none());
+
+        assert_eq!(
+            HidUsage::from_name_in_page(0x01, "Mouse"),
+            HidUsage::from_pageid_uid(0x01, 0x002)
+        );
+        assert!(HidUsage::from_name_in_page(0x01, "mouse").is_none());
+
+        assert_eq!(
+            Dialect::from_name_in_language(0x0009, "UK"),
+            Dialect::from_lid_did(0x0009, 0x02)
+        );
+        assert!(Dialect::from_name_in_language(0xffff, "UK").is_none());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_devices_sorted() {
+        let vendor = Vendor::from_id(0x1d6b).unwrap();
+
+        let ids: Vec<_> = vendor
+            .devices_sorted_by_id()
+            .iter()
+            .map(|d| d.id())
+            .collect();
+        assert_eq!(
+            ids,
+            [0x0001, 0x0002, 0x0003, 0x0100, 0x0101, 0x0102, 0x0103, 0x0104, 0x0105, 0x0200]
+        );
+
+        let names: Vec<_> = vendor
+            .devices_sorted_by_name()
+            .iter()
+            .map(|d| d.name())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "1.1 root hub",
+                "2.0 root hub",
+                "3.0 root hub",
+                "Audio Gadget",
+                "EEM Gadget",
+                "FunctionFS Gadget",
+                "Multifunction Composite Gadget",
+                "NCM (Ethernet) Gadget",
+                "PTP Gadget",
+                "Qemu Audio Device",
+            ]
+        );
+    }
+
+    #[cfg(all(feature = "alloc", not(feature = "vendors-only")))]
+    #[test]
+    fn test_class_iter_sorted() {
+        let classes: Vec<_> = Classes::iter_sorted_by_name().collect();
+        assert_eq!(classes.len(), total_class_count());
+        assert!(classes.windows(2).all(|w| w[0].name() <= w[1].name()));
+
+        let classes: Vec<_> = Classes::iter_sorted_by_id().collect();
+        assert_eq!(classes.first().unwrap().id(), 0x00);
+        assert!(classes.windows(2).all(|w| w[0].id() < w[1].id()));
+    }
+
+    #[cfg(all(feature = "alloc", not(feature = "no-languages")))]
+    #[test]
+    fn test_language_iter_sorted() {
+        let languages: Vec<_> = Languages::iter_sorted_by_name().collect();
+        assert_eq!(languages.len(), Languages::iter().count());
+        assert!(languages.windows(2).all(|w| w[0].name() <= w[1].name()));
+    }
+
+    #[cfg(all(feature = "alloc", not(feature = "no-hid")))]
+    #[test]
+    fn test_hid_usage_page_iter_sorted() {
+        let pages: Vec<_> = HidUsagePages::iter_sorted_by_id().collect();
+        assert_eq!(pages.len(), HidUsagePages::iter().count());
+        assert!(pages.windows(2).all(|w| w[0].id() < w[1].id()));
+    }
+
+    #[test]
+    fn test_database() {
+        let vendor = Database::vendor(0x1d6b).unwrap();
+        assert_eq!(vendor.name(), "Linux Foundation");
+        assert_eq!(Database::device(0x1d6b, 0x0003).unwrap().vendor(), vendor);
+        assert!(Database::device(0x1d6b, 0x9999).is_none());
+        assert_eq!(Database::all_vendors().count(), total_vendor_count());
+        assert_eq!(Database::all_devices().count(), total_device_count());
+    }
+
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    fn test_database_classes() {
+        let protocol = Database::protocol(0x03, 0x01, 0x01).unwrap();
+        assert_eq!(protocol.name(), "Keyboard");
+        assert_eq!(Database::subclass(0x03, 0x01), Some(protocol.subclass()));
+        assert_eq!(Database::class(0x03), Some(protocol.class()));
+        assert_eq!(Database::all_classes().count(), total_class_count());
+        assert_eq!(Database::all_protocols().count(), Protocols::iter().count());
+    }
+
+    #[test]
+    fn test_database_provenance() {
+        assert!(!DATABASE_VERSION.is_empty());
+        assert!(!DATABASE_DATE.is_empty());
+        assert!(DATABASE_DATE.starts_with(&DATABASE_VERSION.replace('.', "-")));
+
+        assert_eq!(DATABASE_HASH.len(), 64);
+        assert!(DATABASE_HASH
+            .chars()
+            .all(|c| matches!(c, '0'..='9' | 'a'..='f')));
+    }
+
+    #[test]
+    fn test_vendor_devices() {
+        let vendor = Vendor::from_id(0x1d6b).unwrap();
+
+        for device in vendor.devices() {
+            assert_eq!(device.vendor(), vendor);
+            assert!(!device.name().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_from_vid_pid() {
+        let device = Device::from_vid_pid(0x1d6b, 0x0003).unwrap();
+
+        assert_eq!(device.name(), "3.0 root hub");
+
+        let (vid, pid) = device.as_vid_pid();
+
+        assert_eq!(vid, device.vendor().id());
+        assert_eq!(pid, device.id());
+
+        let device2 = Device::from_vid_pid(vid, pid).unwrap();
+
+        assert_eq!(device, device2);
+
+        let last_device = Device::from_vid_pid(0xffee, 0x0100).unwrap();
+        assert_eq!(
+            last_device.name(),
+            "Card Reader Controller RTS5101/RTS5111/RTS5116"
+        );
+    }
+
+    #[test]
+    fn test_devices_iter() {
+        let nested = Vendors::iter().map(|v| v.devices().count()).sum::<usize>();
+
+        assert_eq!(Devices::iter().count(), nested);
+        assert!(Devices::iter().any(|d| d.as_vid_pid() == (0x1d6b, 0x0003)));
+    }
+
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    fn test_subclasses_iter() {
+        let nested = Classes::iter()
+            .map(|c| c.sub_classes().count())
+            .sum::<usize>();
+
+        assert_eq!(SubClasses::iter().count(), nested);
+        assert!(SubClasses::iter().any(|s| s.as_cid_scid() == (0x03, 0x01)));
+    }
+
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    fn test_protocols_iter() {
+        let nested = SubClasses::iter()
+            .map(|s| s.protocols().count())
+            .sum::<usize>();
+
+        assert_eq!(Protocols::iter().count(), nested);
+        assert!(Protocols::iter().any(|p| p.name() == "Keyboard"));
+    }
+
+    #[cfg(all(
+        feature = "serde",
+        not(feature = "serde_hex_ids"),
+        not(feature = "no-languages")
+    ))]
+    #[test]
+    fn test_serialize() {
+        let device = Device::from_vid_pid(0x1d6b, 0x0003).unwrap();
+        let value: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(device).unwrap()).unwrap();
+
+        assert_eq!(value["vendor_id"], 0x1d6b);
+        assert_eq!(value["id"], 0x0003);
+        assert_eq!(value["name"], "3.0 root hub");
+
+        let language = Language::from_id(0x0007).unwrap();
+        let value: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(language).unwrap()).unwrap();
+
+        assert_eq!(value["id"], 0x0007);
+        assert_eq!(value["name"], "German");
+        assert!(value["children"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|d| d["id"] == 0x02 && d["name"] == "Swiss"));
+    }
+
+    #[cfg(all(feature = "serde_hex_ids", not(feature = "vendors-only")))]
+    #[test]
+    fn test_serialize_hex_ids() {
+        let device = Device::from_vid_pid(0x1d6b, 0x0003).unwrap();
+        let value: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(device).unwrap()).unwrap();
+
+        assert_eq!(value["vendor_id"], "0x1d6b");
+        assert_eq!(value["id"], "0x0003");
+
+        let class = Class::from_id(0x03).unwrap();
+        let value: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(class).unwrap()).unwrap();
+
+        assert_eq!(value["id"], "0x03");
+        assert_eq!(value["sub_classes"][0]["class_id"], "0x03");
+    }
+
+    #[cfg(all(feature = "serde", not(feature = "vendors-only")))]
+    #[test]
+    fn test_deserialize_ids() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Filter {
+            vendor_id: VendorId,
+            product_id: ProductId,
+            class_id: ClassId,
+        }
+
+        let filter: Filter =
+            serde_json::from_str(r#"{"vendor_id": "0x1d6b", "product_id": "3", "class_id": 9}"#)
+                .unwrap();
+        assert_eq!(
+            Device::from_ids(filter.vendor_id, filter.product_id),
+            Device::from_vid_pid(0x1d6b, 0x0003)
+        );
+        assert_eq!(Class::from_id(filter.class_id), Class::from_id(0x09));
+
+        // Round-trip through whichever representation serialization is configured for.
+        let filter: Filter =
+            serde_json::from_str(&serde_json::to_string(&filter).unwrap()).unwrap();
+        assert_eq!(filter.vendor_id, VendorId(0x1d6b));
+        assert_eq!(filter.product_id, ProductId(0x0003));
+        assert_eq!(filter.class_id, ClassId(0x09));
+
+        for bad in [
+            r#""0x1d6b0""#,
+            r#""65536""#,
+            "65536",
+            "-1",
+            r#""""#,
+            r#""0x""#,
+            r#""+3""#,
+        ] {
+            assert!(
+                serde_json::from_str::<VendorId>(bad).is_err(),
+                "{} should not parse",
+                bad
+            );
+        }
+        assert!(serde_json::from_str::<ClassId>("256").is_err());
+        assert_eq!(
+            serde_json::from_str::<ClassId>(r#""0XfF""#).unwrap(),
+            ClassId(0xff)
+        );
+    }
+
+    #[cfg(all(
+        feature = "defmt",
+        not(any(
+            feature = "no-audio",
+            feature = "no-video",
+            feature = "no-languages",
+            feature = "no-hid",
+            feature = "vendors-only"
+        ))
+    ))]
+    #[test]
+    fn test_defmt_format() {
+        // Actually emitting defmt frames requires a target with a global logger, so this
+        // only checks that every public type can be logged.
+        fn assert_format<T: defmt::Format + ?Sized>() {}
+
+        assert_format::<Vendor>();
+        assert_format::<Device>();
+        assert_format::<Interface>();
+        assert_format::<Class>();
+        assert_format::<SubClass>();
+        assert_format::<Protocol>();
+        assert_format::<AudioTerminal>();
+        assert_format::<Hid>();
+        assert_format::<HidItemType>();
+        assert_format::<HidUsagePage>();
+        assert_format::<HidUsage>();
+        assert_format::<Bias>();
+        assert_format::<Phy>();
+        assert_format::<Language>();
+        assert_format::<Dialect>();
+        assert_format::<HidCountryCode>();
+        assert_format::<VideoTerminal>();
+        assert_format::<UsbIdError>();
+        assert_format::<ParseIdError>();
+        assert_format::<VendorId>();
+        assert_format::<ProductId>();
+        assert_format::<ClassId>();
+        assert_format::<VidPid>();
+        assert_format::<ClassTriple>();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_hex_ids() {
+        // Padding is preserved for small IDs.
+        let device = Device::from_vid_pid(0x1d6b, 0x0001).unwrap();
+        assert_eq!(device.as_vid_pid_hex(), "1d6b:0001");
+        assert_eq!(
+            device.as_vid_pid_hex(),
+            VidPid::from(device.as_vid_pid()).to_string()
+        );
+
+        let vendor = Vendors::iter().find(|v| v.id() < 0x100).unwrap();
+        assert_eq!(vendor.id_as_hex().len(), 6);
+        assert!(vendor.id_as_hex().starts_with("0x00"));
+    }
+
+    #[cfg(all(feature = "alloc", not(feature = "vendors-only")))]
+    #[test]
+    fn test_class_hex_id() {
+        assert_eq!(Class::from_id(0x00).unwrap().id_as_hex(), "0x00");
+        assert_eq!(Class::from_id(0xff).unwrap().id_as_hex(), "0xff");
+    }
+
+    #[test]
+    fn test_vendor_device_display() {
+        let vendor = Vendor::from_id(0x1d6b).unwrap();
+        assert_eq!(vendor.to_string(), "Linux Foundation (0x1d6b)");
+
+        let device = Device::from_vid_pid(0x1d6b, 0x0003).unwrap();
+        assert_eq!(
+            device.to_string(),
+            "Linux Foundation 3.0 root hub (0x1d6b:0x0003)"
+        );
+    }
+
+    #[test]
+    fn test_vendor_device_hex() {
+        let vendor = Vendor::from_id(0x1d6b).unwrap();
+        assert_eq!(format!("{:x}", vendor), "1d6b");
+        assert_eq!(format!("{:X}", vendor), "1D6B");
+
+        let device = Device::from_vid_pid(0x1d6b, 0x0003).unwrap();
+        assert_eq!(format!("{:x}", device), "1d6b:0003");
+        assert_eq!(format!("{:X}", device), "1D6B:0003");
+    }
+
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    fn test_class_family_hex() {
+        let class = Class::from_id(0x0e).unwrap();
+        assert_eq!(format!("{:x}", class), "0e");
+        assert_eq!(format!("{:X}", class), "0E");
+
+        let subclass = SubClass::from_cid_scid(0x0e, 0x01).unwrap();
+        assert_eq!(format!("{:x}", subclass), "0e/01");
+        assert_eq!(format!("{:X}", subclass), "0E/01");
+
+        let protocol = Protocol::from_cid_scid_pid(0xe0, 0x01, 0x01).unwrap();
+        assert_eq!(format!("{:x}", protocol), "e0/01/01");
+        assert_eq!(format!("{:X}", protocol), "E0/01/01");
+    }
+
+    #[cfg(not(any(
+        feature = "no-hid",
+        feature = "no-audio",
+        feature = "no-video",
+        feature = "no-languages"
+    )))]
+    #[test]
+    fn test_leaf_type_hex() {
+        let audio_terminal = AudioTerminal::from_id(0x070a).unwrap();
+        assert_eq!(format!("{:x}", audio_terminal), "070a");
+        assert_eq!(format!("{:X}", audio_terminal), "070A");
+
+        let video_terminal = VideoTerminal::from_id(0x0101).unwrap();
+        assert_eq!(format!("{:x}", video_terminal), "0101");
+
+        let page = HidUsagePage::from_id(0x0c).unwrap();
+        assert_eq!(format!("{:x}", page), "0c");
+        assert_eq!(format!("{:X}", page), "0C");
+
+        let language = Language::from_id(0x000c).unwrap();
+        assert_eq!(format!("{:x}", language), "000c");
+        assert_eq!(format!("{:X}", language), "000C");
+
+        let hid = Hid::from_id(0x22).unwrap();
+        assert_eq!(format!("{:x}", hid), "22");
+    }
+
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    fn test_ord() {
+        let apple = Vendor::from_id(0x05ac).unwrap();
+        let linux = Vendor::from_id(0x1d6b).unwrap();
+        assert!(apple < linux);
+        assert_eq!(linux.cmp(linux), Ordering::Equal);
+
+        let hub2 = Device::from_vid_pid(0x1d6b, 0x0002).unwrap();
+        let hub3 = Device::from_vid_pid(0x1d6b, 0x0003).unwrap();
+        assert!(hub2 < hub3);
+
+        let audio = Class::from_id(0x01).unwrap();
+        let hid = Class::from_id(0x03).unwrap();
+        assert!(audio < hid);
+
+        let boot = SubClass::from_cid_scid(0x03, 0x01).unwrap();
+        let telephone = SubClass::from_cid_scid(0x02, 0x03).unwrap();
+        assert!(telephone < boot);
+    }
+
+    #[cfg(not(any(feature = "no-languages", feature = "no-hid", feature = "vendors-only")))]
+    #[test]
+    fn test_iter_len() {
+        assert!(Vendors::iter().len() > 0);
+        assert_eq!(Vendors::iter().len(), Vendors::iter().count());
+        assert!(Classes::iter().len() > 0);
+        assert!(Languages::iter().len() > 0);
+        assert!(HidUsagePages::iter().len() > 0);
+
+        // The flattened iterators' lengths come from the build script's counts
+        assert_eq!(Devices::iter().len(), Devices::iter().count());
+        assert_eq!(all_interfaces().len(), all_interfaces().count());
+        assert_eq!(SubClasses::iter().len(), SubClasses::iter().count());
+        assert_eq!(Protocols::iter().len(), Protocols::iter().count());
+        assert_eq!(Dialects::iter().len(), Dialects::iter().count());
+        assert_eq!(all_hid_usages().len(), all_hid_usages().count());
+    }
+
+    #[cfg(not(any(feature = "no-languages", feature = "no-hid", feature = "vendors-only")))]
+    #[test]
+    fn test_child_iter_len() {
+        let vendor = Vendor::from_id(0x1d6b).unwrap();
+        assert_eq!(vendor.devices().len(), vendor.devices().count());
+
+        let class = Class::from_id(0x03).unwrap();
+        assert_eq!(class.sub_classes().len(), class.sub_classes().count());
+
+        let subclass = SubClass::from_cid_scid(0x03, 0x01).unwrap();
+        assert_eq!(subclass.protocols().len(), 3);
+
+        let page = HidUsagePage::from_id(0x01).unwrap();
+        assert!(page.usages().len() > 0);
+
+        let language = Language::from_id(0x0007).unwrap();
+        assert!(language.dialects().len() > 0);
+    }
+
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    fn test_iter_rev() {
+        let vendors: Vec<_> = Vendors::iter().collect();
+        assert_eq!(Vendors::iter().next_back(), vendors.last().copied());
+
+        let classes: Vec<_> = Classes::iter().collect();
+        assert_eq!(Classes::iter().next_back(), classes.last().copied());
+
+        let vendor = Vendor::from_id(0x1d6b).unwrap();
+        assert_eq!(vendor.devices().next_back(), vendor.devices.last());
+
+        let class = Class::from_id(0xff).unwrap();
+        let last = class.sub_classes().next_back().unwrap();
+        assert_eq!(last.id(), 0xff);
+    }
+
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    fn test_named_iters() {
+        let iter = Devices::iter();
+        assert_eq!(iter.len(), iter.clone().count());
+        assert!(iter.clone().eq(iter.clone()));
+        assert!(!format!("{:?}", Vendor::from_id(0x1d6b).unwrap().devices()).is_empty());
+
+        let forward: Vec<_> = Protocols::iter().collect();
+        let mut backward: Vec<_> = Protocols::iter().rev().collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+
+        // Draining from both ends should visit every subclass exactly once.
+        let mut iter = SubClasses::iter();
+        let mut seen = vec![];
+        while let Some(subclass) = iter.next() {
+            seen.push(subclass.as_cid_scid());
+            if let Some(subclass) = iter.next_back() {
+                seen.push(subclass.as_cid_scid());
+            }
+            assert_eq!(iter.len(), SubClasses::iter().len() - seen.len());
+        }
+        seen.sort();
+        seen.dedup();
+        assert_eq!(seen.len(), SubClasses::iter().len());
+    }
+
+    #[cfg(not(any(feature = "no-hid", feature = "no-languages")))]
+    #[test]
+    fn test_named_hid_language_iters() {
+        fn assert_iter<I, T>(iter: I)
+        where
+            I: ExactSizeIterator<Item = &'static T>
+                + DoubleEndedIterator
+                + Clone
+                + core::fmt::Debug,
+            T: PartialEq + core::fmt::Debug + 'static,
+        {
+            assert_eq!(iter.len(), iter.clone().count());
+            let forward: Vec<_> = iter.clone().collect();
+            let mut backward: Vec<_> = iter.rev().collect();
+            backward.reverse();
+            assert_eq!(forward, backward);
+        }
+
+        let page: &HidUsagePage = HidUsagePage::from_id(0x01).unwrap();
+        let usages: HidUsageIter = page.usages();
+        assert_iter(usages);
+
+        let language: &Language = Language::from_id(0x0009).unwrap();
+        let dialects: DialectIter = language.dialects();
+        assert_iter(dialects);
+
+        let pages: HidUsagePageIter = HidUsagePages::iter();
+        assert_iter(pages);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_iter_database_order() {
+        // The vendored database is sorted by ID, so database order is ID order.
+        let ids: Vec<_> = Vendors::iter().map(|v| v.id()).collect();
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+        assert!(Vendors::iter().eq(Vendors::iter_sorted_by_id()));
+
+        let ids: Vec<_> = Devices::iter().map(|d| d.as_vid_pid()).collect();
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[cfg(all(
+        feature = "alloc",
+        not(any(feature = "vendors-only", feature = "no-hid", feature = "no-languages"))
+    ))]
+    #[test]
+    fn test_table_iter_database_order() {
+        assert!(Classes::iter().eq(Classes::iter_sorted_by_id()));
+        assert!(HidUsagePages::iter().eq(HidUsagePages::iter_sorted_by_id()));
+
+        let ids: Vec<_> = Languages::iter().map(|l| l.id()).collect();
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+    }
 
     #[test]
-    fn test_from_id() {
+    fn test_iter_clone() {
         let vendor = Vendor::from_id(0x1d6b).unwrap();
+        let iter = vendor.devices();
+        let n = iter.len() / 2;
+        let (first_half, second_half) = (iter.clone().take(n), iter.skip(n));
+        assert!(first_half.chain(second_half).eq(vendor.devices()));
 
-        assert_eq!(vendor.name(), "Linux Foundation");
-        assert_eq!(vendor.id(), 0x1d6b);
+        // A clone taken partway through picks up where the original left off.
+        let mut iter = Vendors::iter();
+        iter.nth(10);
+        let rest: Vec<_> = iter.clone().collect();
+        assert!(iter.eq(rest));
+
+        let mut iter = Devices::iter();
+        iter.nth(100);
+        iter.next_back();
+        let clone = iter.clone();
+        assert_eq!(clone.len(), iter.len());
+        assert!(clone.eq(iter));
     }
 
     #[test]
-    fn test_vendor_devices() {
-        let vendor = Vendor::from_id(0x1d6b).unwrap();
+    fn test_get_children() {
+        for vendor in Vendors::iter() {
+            for device in vendor.devices() {
+                assert_eq!(vendor.get_device(device.id()), Some(device));
+            }
+        }
+        assert!(Vendor::from_id(0x1d6b)
+            .unwrap()
+            .get_device(0xfffe)
+            .is_none());
+    }
 
-        for device in vendor.devices() {
-            assert_eq!(device.vendor(), vendor);
-            assert!(!device.name().is_empty());
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    fn test_get_class_children() {
+        for class in Classes::iter() {
+            for subclass in class.sub_classes() {
+                assert_eq!(class.get_subclass(subclass.id()), Some(subclass));
+                for protocol in subclass.protocols() {
+                    assert_eq!(subclass.get_protocol(protocol.id()), Some(protocol));
+                }
+            }
         }
+        let class = Class::from_id(0x03).unwrap();
+        assert!(class.get_subclass(0xfe).is_none());
+        assert!(class
+            .get_subclass(0x01)
+            .unwrap()
+            .get_protocol(0xfe)
+            .is_none());
     }
 
+    #[cfg(not(any(feature = "no-hid", feature = "no-languages")))]
     #[test]
-    fn test_from_vid_pid() {
-        let device = Device::from_vid_pid(0x1d6b, 0x0003).unwrap();
+    fn test_get_hid_language_children() {
+        let page = HidUsagePage::from_id(0x07).unwrap();
+        assert_eq!(page.get_usage(0x04), HidUsage::from_pageid_uid(0x07, 0x04));
+        assert!(page.get_usage(0xfffe).is_none());
 
-        assert_eq!(device.name(), "3.0 root hub");
+        let language = Language::from_id(0x0009).unwrap();
+        assert_eq!(
+            language.get_dialect(0x01),
+            Dialect::from_lid_did(0x0009, 0x01)
+        );
+        assert!(language.get_dialect(0xfe).is_none());
+    }
 
-        let (vid, pid) = device.as_vid_pid();
+    #[test]
+    fn test_devices_with_interfaces() {
+        for vendor in Vendors::iter() {
+            let expected = vendor
+                .devices()
+                .filter(|d| d.interfaces().next().is_some())
+                .count();
+            assert_eq!(vendor.devices_with_interfaces().count(), expected);
+        }
+    }
 
-        assert_eq!(vid, device.vendor().id());
-        assert_eq!(pid, device.id());
+    #[test]
+    fn test_vendors_iter_with_prefix() {
+        let apple = Vendor::from_id(0x05ac).unwrap();
 
-        let device2 = Device::from_vid_pid(vid, pid).unwrap();
+        let vendors: Vec<_> = Vendors::iter_with_prefix("Apple").collect();
+        assert_eq!(vendors, [apple]);
+        assert_eq!(Vendors::iter_with_prefix("apple").count(), 0);
 
-        assert_eq!(device, device2);
+        let vendors: Vec<_> = Vendors::iter_with_prefix_ci("aPPLE").collect();
+        assert_eq!(vendors, [apple]);
 
-        let last_device = Device::from_vid_pid(0xffee, 0x0100).unwrap();
+        assert!(Vendors::iter_with_prefix("Apple").all(|v| v.name().starts_with("Apple")));
+        assert_eq!(Vendors::iter_with_prefix("").count(), total_vendor_count());
         assert_eq!(
-            last_device.name(),
-            "Card Reader Controller RTS5101/RTS5111/RTS5116"
+            Vendors::iter_with_prefix_ci("").count(),
+            total_vendor_count()
+        );
+        // A prefix longer than every name never matches.
+        assert_eq!(Vendors::iter_with_prefix_ci(&"x".repeat(1000)).count(), 0);
+    }
+
+    #[test]
+    fn test_vendors_iter_with_devices() {
+        assert!(Vendors::iter_with_devices().all(|v| !v.is_empty()));
+        assert_eq!(
+            Vendors::iter_with_devices().count(),
+            Vendors::iter()
+                .filter(|v| v.devices().next().is_some())
+                .count()
+        );
+        assert!(Vendors::iter_with_devices().any(|v| v.id() == 0x1d6b));
+    }
+
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    fn test_classes_iter_with_subclasses() {
+        assert!(Classes::iter_with_subclasses().all(|c| c.sub_classes().next().is_some()));
+        assert_eq!(
+            Classes::iter_with_subclasses().count(),
+            Classes::iter()
+                .filter(|c| c.sub_classes().next().is_some())
+                .count()
         );
+        // Hubs (0x09) have subclasses, while the interface-defined class (0x00) has none.
+        assert!(Classes::iter_with_subclasses().any(|c| c.id() == 0x09));
+        assert!(!Classes::iter_with_subclasses().any(|c| c.id() == 0x00));
+    }
+
+    #[test]
+    fn test_interface_parents() {
+        for device in Devices::iter() {
+            for interface in device.interfaces() {
+                assert_eq!(interface.device(), device);
+                assert_eq!(interface.vendor(), device.vendor());
+            }
+        }
     }
 
+    #[test]
+    fn test_from_vid_pid_all() {
+        for device in Devices::iter() {
+            let (vid, pid) = device.as_vid_pid();
+            assert_eq!(Device::from_vid_pid(vid, pid), Some(device));
+        }
+
+        assert!(Device::from_vid_pid(0x1d6b, 0xffff).is_none());
+        assert!(Device::from_vid_pid(0xffff, 0x0003).is_none());
+    }
+
+    #[cfg(not(feature = "vendors-only"))]
     #[test]
     fn test_class_from_id() {
         let class = Class::from_id(0x03).unwrap();
@@ -701,14 +5765,21 @@ mod tests {
         assert_eq!(class.id(), 0x03);
     }
 
+    #[cfg(not(feature = "vendors-only"))]
     #[test]
     fn test_subclass_from_cid_scid() {
         let subclass = SubClass::from_cid_scid(0x03, 0x01).unwrap();
 
         assert_eq!(subclass.name(), "Boot Interface Subclass");
         assert_eq!(subclass.id(), 0x01);
+
+        for subclass in SubClasses::iter() {
+            let (class_id, id) = subclass.as_cid_scid();
+            assert_eq!(SubClass::from_cid_scid(class_id, id), Some(subclass));
+        }
     }
 
+    #[cfg(not(feature = "vendors-only"))]
     #[test]
     fn test_protocol_from_cid_scid_pid() {
         let protocol = Protocol::from_cid_scid_pid(0x03, 0x01, 0x01).unwrap();
@@ -728,6 +5799,299 @@ mod tests {
         assert_eq!(protocol.id(), 0xff);
     }
 
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    fn test_try_lookups() {
+        assert_eq!(
+            SubClass::try_from_cid_scid(0x02, 0x03),
+            Ok(SubClass::from_cid_scid(0x02, 0x03).unwrap())
+        );
+        assert_eq!(
+            SubClass::try_from_cid_scid(0xfd, 0x00),
+            Err(UsbIdError::ClassNotFound(0xfd))
+        );
+        assert_eq!(
+            Protocol::try_from_cid_scid_pid(0xfd, 0x00, 0x00),
+            Err(UsbIdError::ClassNotFound(0xfd))
+        );
+        assert_eq!(
+            Protocol::try_from_cid_scid_pid(0x02, 0xaa, 0x00),
+            Err(UsbIdError::SubClassNotFound {
+                class_id: 0x02,
+                subclass_id: 0xaa
+            })
+        );
+
+        let err = Protocol::try_from_cid_scid_pid(0x02, 0x02, 0xaa).unwrap_err();
+        assert_eq!(err.to_string(), "subclass 0x02/0x02 has no protocol 0xaa");
+        assert_eq!(
+            UsbIdError::DeviceNotFound {
+                vid: 0x1d6b,
+                pid: 0x9999
+            }
+            .to_string(),
+            "vendor 0x1d6b has no device 0x9999"
+        );
+    }
+
+    #[test]
+    fn test_vid_pid() {
+        let vid_pid: VidPid = "1d6b:0003".parse().unwrap();
+        assert_eq!(vid_pid, VidPid::new(0x1d6b, 0x0003));
+        assert_eq!(vid_pid, (0x1d6b, 0x0003));
+        assert_eq!(<(u16, u16)>::from(vid_pid), (0x1d6b, 0x0003));
+        assert_eq!(VidPid::from((0x1d6b, 0x0003)), vid_pid);
+        assert_eq!(vid_pid.to_string(), "1d6b:0003");
+        assert_eq!(vid_pid.lookup(), Device::from_vid_pid(0x1d6b, 0x0003));
+
+        assert_eq!("1D6B:3".parse(), Ok(VidPid::new(0x1d6b, 0x0003)));
+        for bad in [
+            "",
+            "1d6b",
+            "1d6b:",
+            ":0003",
+            "1d6b:0003:0",
+            "0x1d6b:0003",
+            "1d6b0:0003",
+            "+d6b:0003",
+        ] {
+            assert!(bad.parse::<VidPid>().is_err(), "{:?} should not parse", bad);
+        }
+    }
+
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    fn test_class_triple() {
+        let triple: ClassTriple = "02/02/05".parse().unwrap();
+        assert_eq!(triple, ClassTriple::new(0x02, 0x02, 0x05));
+        assert_eq!(triple, (0x02, 0x02, 0x05));
+        assert_eq!(<(u8, u8, u8)>::from(triple), (0x02, 0x02, 0x05));
+        assert_eq!(
+            (
+                triple.class_id(),
+                triple.subclass_id(),
+                triple.protocol_id()
+            ),
+            (0x02, 0x02, 0x05)
+        );
+        assert_eq!(triple.to_string(), "02/02/05");
+
+        let (class, subclass, protocol) = triple.lookup().unwrap();
+        assert_eq!(class.id(), 0x02);
+        assert_eq!(subclass.as_cid_scid(), (0x02, 0x02));
+        assert_eq!(protocol.as_cid_scid_pid(), (0x02, 0x02, 0x05));
+        assert_eq!(ClassTriple::new(0x02, 0x02, 0xaa).lookup(), None);
+
+        assert_eq!("E0/1/ff".parse(), Ok(ClassTriple::new(0xe0, 0x01, 0xff)));
+        for bad in [
+            "",
+            "02/02",
+            "02/02/05/00",
+            "02/02/",
+            "002/02/05",
+            "0x2/02/05",
+        ] {
+            assert!(
+                bad.parse::<ClassTriple>().is_err(),
+                "{:?} should not parse",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn test_id_newtypes() {
+        assert_eq!(Vendor::from_id(VendorId(0x1d6b)), Vendor::from_id(0x1d6b));
+        assert_eq!(
+            Device::from_ids(VendorId::from(0x1d6b), ProductId::from(0x0003)),
+            Device::from_vid_pid(0x1d6b, 0x0003)
+        );
+        assert_eq!(u16::from(VendorId(0x1d6b)), 0x1d6b);
+        assert_eq!(u16::from(ProductId(0x0003)), 0x0003);
+    }
+
+    #[test]
+    fn test_try_from_vid_pid() {
+        assert_eq!(
+            Device::try_from_vid_pid(0x1d6b, 0x0003),
+            Ok(Device::from_vid_pid(0x1d6b, 0x0003).unwrap())
+        );
+        assert_eq!(
+            Device::try_from_vid_pid(0xfffe, 0x0003),
+            Err(UsbIdError::VendorNotFound(0xfffe))
+        );
+        assert_eq!(
+            Device::try_from_vid_pid(0x1d6b, 0x9999),
+            Err(UsbIdError::DeviceNotFound {
+                vid: 0x1d6b,
+                pid: 0x9999
+            })
+        );
+    }
+
+    #[test]
+    fn test_try_from() {
+        fn lookup(vid: u16, pid: u16) -> Result<&'static str, UsbIdError> {
+            let device = <&Device>::try_from((vid, pid))?;
+            Ok(device.name())
+        }
+
+        assert_eq!(lookup(0x1d6b, 0x0003), Ok("3.0 root hub"));
+        assert_eq!(
+            lookup(0x1d6b, 0x9999),
+            Err(UsbIdError::DeviceNotFound {
+                vid: 0x1d6b,
+                pid: 0x9999
+            })
+        );
+        assert_eq!(
+            lookup(0xfffe, 0x0001),
+            Err(UsbIdError::VendorNotFound(0xfffe))
+        );
+
+        assert_eq!(
+            <&Vendor>::try_from(0x1d6b),
+            Ok(Vendor::from_id(0x1d6b).unwrap())
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_error_boxed() {
+        fn lookup(vid: u16) -> Result<&'static str, Box<dyn std::error::Error>> {
+            Ok(<&Vendor>::try_from(vid)?.name())
+        }
+
+        assert_eq!(lookup(0x1d6b).unwrap(), "Linux Foundation");
+
+        let err = lookup(0xfffe).unwrap_err();
+        assert_eq!(err.to_string(), "no vendor 0xfffe in the USB database");
+        assert!(err.source().is_none());
+        assert_eq!(
+            err.downcast_ref::<UsbIdError>(),
+            Some(&UsbIdError::VendorNotFound(0xfffe))
+        );
+    }
+
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    fn test_class_try_from() {
+        assert_eq!(<&Class>::try_from(0x09), Ok(Class::from_id(0x09).unwrap()));
+        assert_eq!(
+            <&Class>::try_from(0xfd),
+            Err(UsbIdError::ClassNotFound(0xfd))
+        );
+    }
+
+    #[cfg(not(any(feature = "no-audio", feature = "no-video", feature = "no-languages")))]
+    #[test]
+    fn test_u16_table_try_from() {
+        fn name(id: u16) -> Result<&'static str, UsbIdError> {
+            Ok(<&AudioTerminal>::try_from(id)?.name())
+        }
+
+        assert_eq!(name(0x0201), Ok("Microphone"));
+        assert_eq!(name(0xfffe), Err(UsbIdError::AudioTerminalNotFound(0xfffe)));
+        assert_eq!(
+            name(0xfffe).unwrap_err().to_string(),
+            "no audio terminal type 0xfffe in the USB database"
+        );
+
+        assert_eq!(
+            <&VideoTerminal>::try_from(0x0101),
+            Ok(VideoTerminal::from_id(0x0101).unwrap())
+        );
+        assert_eq!(
+            <&VideoTerminal>::try_from(0xfffe),
+            Err(UsbIdError::VideoTerminalNotFound(0xfffe))
+        );
+        assert_eq!(
+            <&Language>::try_from(0x0007),
+            Ok(Language::from_id(0x0007).unwrap())
+        );
+        assert_eq!(
+            <&Language>::try_from(0xfffe),
+            Err(UsbIdError::LanguageNotFound(0xfffe))
+        );
+    }
+
+    #[cfg(not(feature = "no-hid"))]
+    #[test]
+    fn test_hid_tables_try_from() {
+        assert_eq!(
+            <&HidUsagePage>::try_from(0x01),
+            Ok(HidUsagePage::from_id(0x01).unwrap())
+        );
+        assert_eq!(
+            <&HidUsagePage>::try_from(0xfe),
+            Err(UsbIdError::HidUsagePageNotFound(0xfe))
+        );
+        assert_eq!(
+            <&HidUsagePage>::try_from(0xfe).unwrap_err().to_string(),
+            "no HID usage page 0xfe in the USB database"
+        );
+
+        assert_eq!(<&Hid>::try_from(0x22), Ok(Hid::from_id(0x22).unwrap()));
+        assert_eq!(<&Hid>::try_from(0xfe), Err(UsbIdError::HidNotFound(0xfe)));
+        assert_eq!(
+            <&HidItemType>::try_from(0xfe),
+            Err(UsbIdError::HidItemTypeNotFound(0xfe))
+        );
+        assert_eq!(<&Bias>::try_from(0x02), Ok(Bias::from_id(0x02).unwrap()));
+        assert_eq!(<&Bias>::try_from(0xfe), Err(UsbIdError::BiasNotFound(0xfe)));
+        assert_eq!(<&Phy>::try_from(0xfe), Err(UsbIdError::PhyNotFound(0xfe)));
+        assert_eq!(
+            <&HidCountryCode>::try_from(0x29),
+            Ok(HidCountryCode::from_id(0x29).unwrap())
+        );
+        assert_eq!(
+            <&HidCountryCode>::try_from(0xfe),
+            Err(UsbIdError::HidCountryCodeNotFound(0xfe))
+        );
+    }
+
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    fn test_protocol_parents() {
+        let protocol = Protocol::from_cid_scid_pid(0x03, 0x01, 0x02).unwrap();
+
+        assert_eq!(protocol.as_cid_scid_pid(), (0x03, 0x01, 0x02));
+        assert_eq!(protocol.class(), Class::from_id(0x03).unwrap());
+        assert_eq!(
+            protocol.subclass(),
+            SubClass::from_cid_scid(0x03, 0x01).unwrap()
+        );
+
+        for protocol in Protocols::iter() {
+            let (class_id, subclass_id, id) = protocol.as_cid_scid_pid();
+            assert_eq!(protocol.subclass().as_cid_scid(), (class_id, subclass_id));
+            assert_eq!(
+                Protocol::from_cid_scid_pid(class_id, subclass_id, id),
+                Some(protocol)
+            );
+        }
+    }
+
+    #[cfg(not(feature = "vendors-only"))]
+    #[test]
+    fn test_class_family_display() {
+        let class = Class::from_id(0x03).unwrap();
+        assert_eq!(class.to_string(), "Human Interface Device (0x03)");
+
+        let subclass = SubClass::from_cid_scid(0x03, 0x01).unwrap();
+        assert_eq!(
+            subclass.to_string(),
+            "Human Interface Device / Boot Interface Subclass (0x03/0x01)"
+        );
+
+        let protocol = Protocol::from_cid_scid_pid(0x03, 0x01, 0x01).unwrap();
+        assert_eq!(
+            protocol.to_string(),
+            "Human Interface Device / Boot Interface Subclass / Keyboard (0x03/0x01/0x01)"
+        );
+    }
+
+    #[cfg(not(feature = "no-audio"))]
     #[test]
     fn test_at_from_id() {
         let at = AudioTerminal::from_id(0x0713).unwrap();
@@ -736,6 +6100,69 @@ mod tests {
         assert_eq!(at.id(), 0x0713);
     }
 
+    #[cfg(not(feature = "no-audio"))]
+    #[test]
+    fn test_audio_terminals_iter() {
+        assert!(AudioTerminals::iter().any(|at| at.id() == 0x0201 && at.name() == "Microphone"));
+    }
+
+    #[cfg(not(feature = "no-audio"))]
+    #[test]
+    fn test_audio_terminal_categories() {
+        let category = |id| {
+            let at = AudioTerminal::from_id(id).unwrap();
+            (
+                at.is_input(),
+                at.is_output(),
+                at.is_bidirectional(),
+                at.is_external(),
+            )
+        };
+
+        assert_eq!(category(0x0101), (false, false, false, false)); // USB Streaming
+        assert_eq!(category(0x0201), (true, false, false, false)); // Microphone
+        assert_eq!(category(0x0301), (false, true, false, false)); // Speaker
+        assert_eq!(category(0x0401), (false, false, true, false)); // Handset
+        assert_eq!(category(0x0601), (false, false, false, true)); // Analog Connector
+
+        // No terminal type is in more than one category.
+        for at in AudioTerminals::iter() {
+            let flags = [
+                at.is_input(),
+                at.is_output(),
+                at.is_bidirectional(),
+                at.is_external(),
+            ];
+            assert!(flags.iter().filter(|&&f| f).count() <= 1);
+        }
+    }
+
+    #[cfg(not(feature = "no-audio"))]
+    #[test]
+    fn test_audio_terminal_usb_types_uncategorized() {
+        // The USB terminal types (0x01xx) are part of the stream, not its source or sink
+        let usb_types: Vec<_> = AudioTerminals::iter()
+            .filter(|at| at.id() >> 8 == 0x01)
+            .collect();
+        assert!(usb_types.iter().any(|at| at.name() == "USB Streaming"));
+        for at in usb_types {
+            assert!(!at.is_input() && !at.is_output());
+            assert!(!at.is_bidirectional() && !at.is_external());
+        }
+    }
+
+    #[cfg(not(any(feature = "no-video", feature = "no-hid")))]
+    #[test]
+    fn test_leaf_type_iters() {
+        assert!(Hids::iter().any(|hid| hid.id() == 0x22 && hid.name() == "Report"));
+        assert!(HidItemTypes::iter().any(|t| t.id() == 0xb4 && t.name() == "Pop"));
+        assert!(Biases::iter().any(|b| b.id() == 0x02 && b.name() == "Left Hand"));
+        assert!(Phys::iter().any(|p| p.id() == 0x25 && p.name() == "Fifth Toe"));
+        assert!(HidCountryCodes::iter().any(|cc| cc.id() == 0x29 && cc.name() == "Switzerland"));
+        assert!(VideoTerminals::iter().any(|vt| vt.id() == 0x0101 && vt.name() == "USB Streaming"));
+    }
+
+    #[cfg(not(feature = "no-hid"))]
     #[test]
     fn test_hid_from_id() {
         let hid = Hid::from_id(0x23).unwrap();
@@ -744,6 +6171,7 @@ mod tests {
         assert_eq!(hid.id(), 0x23);
     }
 
+    #[cfg(not(feature = "no-hid"))]
     #[test]
     fn test_hid_type_from_id() {
         let hid_type = HidItemType::from_id(0xc0).unwrap();
@@ -752,6 +6180,7 @@ mod tests {
         assert_eq!(hid_type.id(), 0xc0);
     }
 
+    #[cfg(not(feature = "no-hid"))]
     #[test]
     fn test_bias_from_id() {
         let bias = Bias::from_id(0x04).unwrap();
@@ -760,6 +6189,7 @@ mod tests {
         assert_eq!(bias.id(), 0x04);
     }
 
+    #[cfg(not(feature = "no-hid"))]
     #[test]
     fn test_phy_from_id() {
         let phy = Phy::from_id(0x27).unwrap();
@@ -768,6 +6198,7 @@ mod tests {
         assert_eq!(phy.id(), 0x27);
     }
 
+    #[cfg(not(feature = "no-hid"))]
     #[test]
     fn test_hid_usages_from_id() {
         let hid_usage_page = HidUsagePage::from_id(0x0d).unwrap();
@@ -779,8 +6210,85 @@ mod tests {
 
         assert_eq!(hid_usage.name(), "Digitizer");
         assert_eq!(hid_usage.id(), 0x01);
+        assert_eq!(hid_usage.page(), hid_usage_page);
+        assert_eq!(hid_usage.as_pageid_uid(), (0x0d, 0x01));
+        assert_eq!(hid_usage.qualified_id(), 0x000d_0001);
+    }
+
+    #[cfg(not(feature = "no-hid"))]
+    #[test]
+    fn test_hid_usage_qualified_ids_unique() {
+        let mut ids: Vec<_> = all_hid_usages().map(|u| u.qualified_id()).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), all_hid_usages().count());
+    }
+
+    #[cfg(not(feature = "no-hid"))]
+    #[test]
+    fn test_hid_family_display() {
+        assert_eq!(Hid::from_id(0x22).unwrap().to_string(), "Report (0x22)");
+        assert_eq!(Hid::from_id(0x23).unwrap().to_string(), "Physical (0x23)");
+
+        assert_eq!(
+            HidItemType::from_id(0xb4).unwrap().to_string(),
+            "Pop (0xb4)"
+        );
+        assert_eq!(
+            HidItemType::from_id(0xc0).unwrap().to_string(),
+            "End Collection (0xc0)"
+        );
+
+        assert_eq!(Bias::from_id(0x02).unwrap().to_string(), "Left Hand (0x02)");
+        assert_eq!(
+            Bias::from_id(0x04).unwrap().to_string(),
+            "Either Hand (0x04)"
+        );
+
+        assert_eq!(Phy::from_id(0x25).unwrap().to_string(), "Fifth Toe (0x25)");
+        assert_eq!(Phy::from_id(0x27).unwrap().to_string(), "Cheek (0x27)");
+
+        assert_eq!(
+            HidCountryCode::from_id(0x29).unwrap().to_string(),
+            "Switzerland (0x29)"
+        );
+        assert_eq!(
+            HidCountryCode::from_id(0x00).unwrap().to_string(),
+            "Not supported (0x00)"
+        );
+
+        assert_eq!(
+            HidUsagePage::from_id(0x01).unwrap().to_string(),
+            "Generic Desktop Controls (0x01)"
+        );
+        assert_eq!(
+            HidUsagePage::from_id(0x0d).unwrap().to_string(),
+            "Digitizer (0x0d)"
+        );
+
+        assert_eq!(
+            HidUsage::from_pageid_uid(0x01, 0x0002).unwrap().to_string(),
+            "Generic Desktop Controls / Mouse (0x01/0x0002)"
+        );
+        assert_eq!(
+            HidUsage::from_pageid_uid(0x0d, 0x0001).unwrap().to_string(),
+            "Digitizer / Digitizer (0x0d/0x0001)"
+        );
+    }
+
+    #[cfg(not(any(feature = "no-languages", feature = "no-hid")))]
+    #[test]
+    fn test_hash() {
+        use std::collections::HashSet;
+
+        let hids: HashSet<_> = Hids::iter().chain(Hids::iter()).collect();
+        assert_eq!(hids.len(), Hids::iter().count());
+
+        let languages: HashSet<_> = Languages::iter().collect();
+        assert!(languages.contains(Language::from_id(0x0007).unwrap()));
     }
 
+    #[cfg(not(feature = "no-languages"))]
     #[test]
     fn test_language_from_id() {
         let language = Language::from_id(0x0007).unwrap();
@@ -792,8 +6300,57 @@ mod tests {
 
         assert_eq!(dialect.name(), "Swiss");
         assert_eq!(dialect.id(), 0x02);
+        assert_eq!(dialect.language(), language);
+        assert_eq!(dialect.as_lid_did(), (0x0007, 0x02));
+    }
+
+    #[cfg(not(feature = "no-languages"))]
+    #[test]
+    fn test_language_children() {
+        fn child_names<T: Copy, C: AsRef<str>>(parent: &UsbIdWithChildren<T, C>) -> Vec<&str> {
+            parent.children().map(|c| c.as_ref()).collect()
+        }
+
+        let language = Language::from_id(0x0007).unwrap();
+
+        assert_eq!(language.children_count(), language.dialects().count());
+        assert!(!language.is_empty());
+        assert!(child_names(language).contains(&"Swiss"));
+
+        // Some languages have no dialects at all.
+        let empty = Languages::iter().find(|l| l.is_empty()).unwrap();
+        assert_eq!(empty.children_count(), 0);
+        assert_eq!(empty.children().count(), 0);
     }
 
+    #[cfg(not(feature = "no-languages"))]
+    #[test]
+    fn test_dialects_iter() {
+        let nested = Languages::iter()
+            .map(|l| l.dialects().count())
+            .sum::<usize>();
+
+        assert_eq!(Dialects::iter().count(), nested);
+        assert!(Dialects::iter().any(|d| d.name() == "Swiss"));
+    }
+
+    #[cfg(not(any(feature = "no-audio", feature = "no-video", feature = "no-languages")))]
+    #[test]
+    fn test_language_family_display() {
+        let language = Language::from_id(0x0007).unwrap();
+        assert_eq!(language.to_string(), "German (0x0007)");
+
+        let dialect = Dialect::from_lid_did(0x0007, 0x02).unwrap();
+        assert_eq!(dialect.to_string(), "German / Swiss (0x0007/0x02)");
+
+        let audio_terminal = AudioTerminal::from_id(0x0201).unwrap();
+        assert_eq!(audio_terminal.to_string(), "Microphone (0x0201)");
+
+        let video_terminal = VideoTerminal::from_id(0x0101).unwrap();
+        assert_eq!(video_terminal.to_string(), "USB Streaming (0x0101)");
+    }
+
+    #[cfg(not(feature = "no-hid"))]
     #[test]
     fn test_hid_country_code_from_id() {
         let hid_country_code = HidCountryCode::from_id(0x29).unwrap();
@@ -805,6 +6362,7 @@ mod tests {
         assert_eq!(hid_country_code.name(), "Not supported");
     }
 
+    #[cfg(not(feature = "no-video"))]
     #[test]
     fn test_video_terminal_from_id() {
         let video_terminal = VideoTerminal::from_id(0x0100).unwrap();
@@ -815,4 +6373,17 @@ mod tests {
         let video_terminal = VideoTerminal::from_id(0x0403).unwrap();
         assert_eq!(video_terminal.name(), "Component Video");
     }
+
+    #[cfg(not(feature = "no-video"))]
+    #[test]
+    fn test_video_terminal_categories() {
+        let camera = VideoTerminal::from_id(0x0201).unwrap();
+        assert!(camera.is_input() && !camera.is_output());
+
+        let display = VideoTerminal::from_id(0x0301).unwrap();
+        assert!(display.is_output() && !display.is_input());
+
+        let streaming = VideoTerminal::from_id(0x0101).unwrap();
+        assert!(!streaming.is_input() && !streaming.is_output());
+    }
 }
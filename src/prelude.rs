@@ -0,0 +1,23 @@
+//! Re-exports of the most commonly used types and traits, for glob importing.
+//!
+//! ```
+//! use usb_ids::prelude::*;
+//!
+//! let vendor = Vendor::from_id(0x1d6b).unwrap();
+//! assert_eq!(vendor.name(), "Linux Foundation");
+//! assert!(Vendors::iter().any(|v| v == vendor));
+//! ```
+//!
+//! Everything here is named after the USB database's contents, so it doesn't shadow
+//! anything in the standard library prelude.
+
+pub use crate::{Database, Device, Devices, FromId, Interface, Vendor, Vendors};
+
+#[cfg(not(feature = "vendors-only"))]
+pub use crate::{Class, Classes, Protocol, Protocols, SubClass, SubClasses};
+
+#[cfg(not(feature = "no-hid"))]
+pub use crate::{HidUsage, HidUsagePage, HidUsagePages};
+
+#[cfg(not(feature = "no-languages"))]
+pub use crate::{Dialect, Dialects, Language, Languages};
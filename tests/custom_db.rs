@@ -0,0 +1,120 @@
+//! Checks building against a custom database; run with
+//! `USB_IDS_PATH=tests/data/usb.ids cargo test --test custom_db`.
+//!
+//! `tests/data/malformed.ids` should give the same results, since its malformed lines are
+//! skipped, as should `tests/data/headerless.ids`, apart from its missing version and date.
+//! These tests do nothing when built against any other database.
+
+use usb_ids::{Device, FromId, Vendor, Vendors};
+
+fn using_test_db() -> bool {
+    option_env!("USB_IDS_PATH").map_or(false, |path| {
+        let path = path.replace('\\', "/");
+        path.ends_with("tests/data/usb.ids")
+            || path.ends_with("tests/data/malformed.ids")
+            || path.ends_with("tests/data/headerless.ids")
+    })
+}
+
+fn using_headerless_db() -> bool {
+    option_env!("USB_IDS_PATH").map_or(false, |path| {
+        path.replace('\\', "/")
+            .ends_with("tests/data/headerless.ids")
+    })
+}
+
+#[test]
+fn test_custom_db_provenance() {
+    if !using_test_db() {
+        return;
+    }
+
+    if using_headerless_db() {
+        assert_eq!(usb_ids::DATABASE_VERSION, "");
+        assert_eq!(usb_ids::DATABASE_DATE, "");
+    } else {
+        assert_eq!(usb_ids::DATABASE_VERSION, "2000.01.01");
+        assert_eq!(usb_ids::DATABASE_DATE, "2000-01-01 00:00:00");
+    }
+    assert_eq!(usb_ids::total_vendor_count(), 2);
+    assert_eq!(usb_ids::total_device_count(), 2);
+    assert_eq!(usb_ids::INTERFACE_COUNT, 1);
+}
+
+#[test]
+fn test_custom_db_lookups() {
+    if !using_test_db() {
+        return;
+    }
+
+    let vendor = Vendor::from_id(0x0001).unwrap();
+    assert_eq!(vendor.name(), "Test Vendor");
+
+    let device = Device::from_vid_pid(0x0001, 0x0001).unwrap();
+    assert_eq!(device.name(), "Test Device");
+    assert_eq!(device.interfaces().next().unwrap().name(), "Test Interface");
+    assert_eq!(
+        vendor.devices_with_interfaces().collect::<Vec<_>>(),
+        [device]
+    );
+
+    assert_eq!(Vendors::iter().count(), 2);
+    assert!(Vendor::from_id(0x1d6c).is_none());
+}
+
+#[test]
+fn test_custom_db_interface_class() {
+    if !using_test_db() {
+        return;
+    }
+
+    // The database doesn't record interfaces' classes
+    let interface = Device::from_vid_pid(0x0001, 0x0001)
+        .unwrap()
+        .interfaces()
+        .next()
+        .unwrap();
+    assert_eq!(
+        (
+            interface.class_id(),
+            interface.subclass_id(),
+            interface.protocol_id()
+        ),
+        (0, 0, 0)
+    );
+    #[cfg(not(feature = "vendors-only"))]
+    {
+        assert!(interface.class().is_none());
+        assert!(interface.protocol().is_none());
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_custom_db_search_interfaces() {
+    if !using_test_db() {
+        return;
+    }
+
+    let results = usb_ids::search_all("test interface");
+    assert_eq!(
+        results.interfaces().map(|i| i.name()).collect::<Vec<_>>(),
+        ["Test Interface"]
+    );
+}
+
+#[cfg(not(feature = "vendors-only"))]
+#[test]
+fn test_custom_db_classes() {
+    if !using_test_db() {
+        return;
+    }
+
+    assert_eq!(usb_ids::total_class_count(), 1);
+    assert_eq!(
+        usb_ids::Protocol::from_cid_scid_pid(0x03, 0x01, 0x01)
+            .unwrap()
+            .name(),
+        "Keyboard"
+    );
+}
@@ -0,0 +1,95 @@
+//! Checks merging extra entries into a custom database; run with
+//! `USB_IDS_PATH=tests/data/usb.ids USB_IDS_EXTRA_PATH=tests/data/extra.ids cargo test --test extra_db`.
+//!
+//! These tests do nothing when built against any other database.
+
+use usb_ids::{Device, FromId, Vendor, Vendors};
+
+fn path_ends_with(path: Option<&str>, suffix: &str) -> bool {
+    matches!(path, Some(path) if path.replace('\\', "/").ends_with(suffix))
+}
+
+fn using_extra_db() -> bool {
+    path_ends_with(option_env!("USB_IDS_PATH"), "tests/data/usb.ids")
+        && path_ends_with(option_env!("USB_IDS_EXTRA_PATH"), "tests/data/extra.ids")
+}
+
+#[test]
+fn test_extra_db_counts() {
+    if !using_extra_db() {
+        return;
+    }
+
+    // The extra file's header doesn't override the main database's
+    assert_eq!(usb_ids::DATABASE_VERSION, "2000.01.01");
+    assert_eq!(usb_ids::total_vendor_count(), 3);
+    assert_eq!(usb_ids::total_device_count(), 5);
+    assert_eq!(usb_ids::INTERFACE_COUNT, 1);
+}
+
+#[test]
+fn test_extra_db_vendors() {
+    if !using_extra_db() {
+        return;
+    }
+
+    // Overwritten entries keep their existing children
+    let vendor = Vendor::from_id(0x0001).unwrap();
+    assert_eq!(vendor.name(), "Renamed Vendor");
+    let device = Device::from_vid_pid(0x0001, 0x0001).unwrap();
+    assert_eq!(device.name(), "Test Device");
+    assert_eq!(device.interfaces().next().unwrap().name(), "Test Interface");
+    assert_eq!(vendor.device_ids().collect::<Vec<_>>(), [0x0001, 0x0002]);
+
+    assert_eq!(
+        Device::from_vid_pid(0x1d6b, 0x0002).unwrap().name(),
+        "2.0 root hub (renamed)"
+    );
+    assert_eq!(
+        Device::from_vid_pid(0x1d6b, 0x0003).unwrap().name(),
+        "3.0 root hub"
+    );
+
+    // New entries are kept in ID order
+    let ids: Vec<_> = Vendors::iter().map(|v| v.id()).collect();
+    assert_eq!(ids, [0x0001, 0x0002, 0x1d6b]);
+    assert_eq!(
+        Device::from_vid_pid(0x0002, 0x0001).unwrap().name(),
+        "Extra Vendor Device"
+    );
+}
+
+#[cfg(not(feature = "vendors-only"))]
+#[test]
+fn test_extra_db_classes() {
+    if !using_extra_db() {
+        return;
+    }
+
+    assert_eq!(usb_ids::total_class_count(), 2);
+    assert_eq!(usb_ids::PROTOCOL_COUNT, 2);
+    assert_eq!(
+        usb_ids::Protocol::from_cid_scid_pid(0x03, 0x01, 0x01)
+            .unwrap()
+            .name(),
+        "Keyboard"
+    );
+    assert_eq!(
+        usb_ids::Protocol::from_cid_scid_pid(0x03, 0x01, 0x02)
+            .unwrap()
+            .name(),
+        "Mouse"
+    );
+    assert_eq!(usb_ids::Class::from_id(0x09).unwrap().name(), "Hub");
+}
+
+#[cfg(not(feature = "no-languages"))]
+#[test]
+fn test_extra_db_languages() {
+    if !using_extra_db() {
+        return;
+    }
+
+    let language = usb_ids::Language::from_id(0x0009).unwrap();
+    assert_eq!(language.dialect_ids().collect::<Vec<_>>(), [0x01, 0x02]);
+}
@@ -0,0 +1,34 @@
+//! Checks that lookups work in a `no_std` crate; run with `--no-default-features`.
+
+#![no_std]
+
+use usb_ids::{Device, FromId, Vendor, Vendors};
+
+#[test]
+fn test_lookups() {
+    let vendor = Vendor::from_id(0x1d6b).unwrap();
+    assert_eq!(vendor.name(), "Linux Foundation");
+    assert!(vendor.devices().any(|d| d.id() == 0x0003));
+
+    let device = Device::from_vid_pid(0x1d6b, 0x0003).unwrap();
+    assert_eq!(device.name(), "3.0 root hub");
+    assert_eq!(device.vendor(), vendor);
+
+    assert_eq!(Vendors::iter().count(), usb_ids::total_vendor_count());
+}
+
+#[cfg(not(feature = "vendors-only"))]
+#[test]
+fn test_class_lookups() {
+    use usb_ids::{Class, Protocol, SubClass};
+
+    let class = Class::from_id(0x03).unwrap();
+    assert_eq!(class.name(), "Human Interface Device");
+    assert_eq!(SubClass::from_cid_scid(0x03, 0x01).unwrap().class(), class);
+    assert_eq!(
+        Protocol::from_cid_scid_pid(0x03, 0x01, 0x01)
+            .unwrap()
+            .name(),
+        "Keyboard"
+    );
+}
@@ -0,0 +1,42 @@
+//! Property tests checking that every lookup returns an entry with the IDs it was
+//! looked up by, i.e. that the generated maps' keys match their values.
+
+use proptest::prelude::*;
+use usb_ids::{Device, FromId, Vendor};
+
+proptest! {
+    #[test]
+    fn vendor_from_id_round_trips(vid: u16) {
+        if let Some(vendor) = Vendor::from_id(vid) {
+            prop_assert_eq!(vendor.id(), vid);
+        }
+    }
+
+    #[test]
+    fn device_from_vid_pid_round_trips(vid: u16, pid: u16) {
+        if let Some(device) = Device::from_vid_pid(vid, pid) {
+            prop_assert_eq!(device.as_vid_pid(), (vid, pid));
+        }
+    }
+
+    // Random pairs almost never name a real device, so also check real vendors' devices
+    #[test]
+    fn known_vendor_devices_round_trip(index: prop::sample::Index, pid: u16) {
+        let vendors: Vec<_> = usb_ids::Vendors::iter().collect();
+        let vid = vendors[index.index(vendors.len())].id();
+        match Device::from_vid_pid(vid, pid) {
+            Some(device) => prop_assert_eq!(device.as_vid_pid(), (vid, pid)),
+            None => prop_assert!(!Vendor::from_id(vid).unwrap().has_device(pid)),
+        }
+    }
+}
+
+#[cfg(not(feature = "vendors-only"))]
+proptest! {
+    #[test]
+    fn subclass_from_cid_scid_round_trips(class_id: u8, subclass_id: u8) {
+        if let Some(subclass) = usb_ids::SubClass::from_cid_scid(class_id, subclass_id) {
+            prop_assert_eq!(subclass.as_cid_scid(), (class_id, subclass_id));
+        }
+    }
+}